@@ -6,7 +6,9 @@ use bevy::render::camera::ScalingMode;
 use bevy_tweening::lens::{TransformPositionLens, TransformScaleLens};
 use bevy_tweening::{Animator, EaseFunction, Tween};
 
-use crate::constants::{CAMERA_MOVEMENT_SPEED, CAMERA_SPAWN_POINT};
+use crate::constants::{
+    CAMERA_CREEP_SPEED, CAMERA_MOVEMENT_SPEED, CAMERA_SPAWN_POINT, PLAYER_SPAWN_POINT,
+};
 use crate::player::Player;
 use crate::states::AppState;
 
@@ -21,7 +23,19 @@ impl Plugin for CameraPlugin {
 }
 
 #[derive(Component)]
-pub struct Camera;
+pub struct Camera {
+    /// The x the camera looks at. It creeps forward on its own and never goes back,
+    /// so a player who dawdles falls behind the camera.
+    pub focus_x: f32,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            focus_x: PLAYER_SPAWN_POINT.x,
+        }
+    }
+}
 
 fn spawn_camera(mut commands: Commands) {
     let mut transform = Transform::from_xyz(-1., 3.5, 4.).looking_at(Vec3::ZERO, Vec3::Y);
@@ -49,26 +63,34 @@ fn spawn_camera(mut commands: Commands) {
             tonemapping: Tonemapping::SomewhatBoringDisplayTransform,
             ..Default::default()
         },
-        Camera,
+        Camera::default(),
         animator,
     ));
 }
 
 fn follow_player(
-    mut cameras: Query<(&mut Animator<Transform>, &Transform), With<Camera>>,
+    time: Res<Time>,
+    mut cameras: Query<(&mut Camera, &mut Animator<Transform>, &Transform)>,
     players: Query<&Transform, With<Player>>,
 ) {
-    let Some((mut camera_animator, camera_transform)) = cameras.iter_mut().next() else {
+    let Some((mut camera, mut camera_animator, camera_transform)) = cameras.iter_mut().next()
+    else {
+        return;
+    };
+    let Some(player_transform) = players.iter().next() else {
         return;
     };
 
-    if camera_transform.scale != Vec3::ONE || camera_animator.tweenable().progress() <= 0.05 {
+    if camera_transform.scale != Vec3::ONE {
         return;
     }
 
-    let Some(player_transform) = players.iter().next() else {
+    camera.focus_x = (camera.focus_x + CAMERA_CREEP_SPEED * time.delta_seconds())
+        .max(player_transform.translation.x);
+
+    if camera_animator.tweenable().progress() <= 0.05 {
         return;
-    };
+    }
 
     camera_animator.set_tweenable(Tween::new(
         EaseFunction::QuadraticOut,
@@ -76,7 +98,7 @@ fn follow_player(
         TransformPositionLens {
             start: camera_transform.translation,
             end: Vec3::new(
-                player_transform.translation.x + CAMERA_SPAWN_POINT.x,
+                camera.focus_x + CAMERA_SPAWN_POINT.x,
                 CAMERA_SPAWN_POINT.y,
                 CAMERA_SPAWN_POINT.z + player_transform.translation.z / 5.,
             ),
@@ -84,13 +106,12 @@ fn follow_player(
     ));
 }
 
-fn translate_to_spawn(
-    mut cameras: Query<(&mut Animator<Transform>, &mut Transform), With<Camera>>,
-) {
-    let Some((mut animator, mut transform)) = cameras.iter_mut().next() else {
+fn translate_to_spawn(mut cameras: Query<(&mut Camera, &mut Animator<Transform>, &mut Transform)>) {
+    let Some((mut camera, mut animator, mut transform)) = cameras.iter_mut().next() else {
         return;
     };
 
+    *camera = Camera::default();
     animator.tweenable_mut().set_progress(1.);
     transform.translation = CAMERA_SPAWN_POINT;
 }
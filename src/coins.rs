@@ -0,0 +1,153 @@
+use std::fs;
+use std::io::ErrorKind;
+
+use bevy::app::{App, AppExit, Last, Plugin, Update};
+use bevy::math::Vec3;
+use bevy::prelude::{
+    in_state, Commands, Component, EventReader, IntoSystemConfigs, OnEnter, Query, Res, ResMut,
+    Resource, Transform, With,
+};
+use bevy::time::Time;
+use bevy_tweening::lens::{TransformPositionLens, TransformScaleLens};
+use bevy_tweening::{Animator, EaseFunction, Tracks, Tween};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{
+    COIN_PICKUP_DURATION, COIN_PICKUP_HEIGHT, COIN_SPIN_SPEED, WALLET_FILE_NAME,
+};
+use crate::events::RequestCoinPickup;
+use crate::states::AppState;
+use crate::utils;
+
+pub struct CoinPlugin;
+
+impl Plugin for CoinPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Wallet::load())
+            .add_systems(OnEnter(AppState::Clearing), finish_run)
+            .add_systems(Last, save_on_exit)
+            .add_systems(
+                Update,
+                (pick_up_coins, spin_coins).run_if(in_state(AppState::Playing)),
+            );
+    }
+}
+
+/// Spawned by the regions and despawned together with their chunks.
+#[derive(Component)]
+pub struct Coin;
+
+/// Coins of the current run and of all runs ever played.
+#[derive(Debug, Default, Resource)]
+pub struct Wallet {
+    /// Coins of the current run are only counted in memory until the run is finished,
+    /// or the game is closed mid-run.
+    pub run_coins: u32,
+    pub total_coins: u64,
+}
+
+#[derive(Deserialize, Serialize)]
+struct WalletSave {
+    coins: u64,
+}
+
+impl Wallet {
+    /// Starts with an empty wallet if there is no save yet or it can't be read.
+    fn load() -> Self {
+        let path = utils::get_save_path(WALLET_FILE_NAME);
+        let save = match fs::read_to_string(&path) {
+            Ok(save) => save,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Self::default(),
+            Err(error) => {
+                warn!("Failed to read the wallet from {}: {error}", path.display());
+                return Self::default();
+            }
+        };
+
+        match serde_json::from_str::<WalletSave>(&save) {
+            Ok(save) => Self {
+                total_coins: save.coins,
+                ..Default::default()
+            },
+            Err(error) => {
+                warn!("Ignoring invalid wallet in {}: {error}", path.display());
+                Self::default()
+            }
+        }
+    }
+
+    fn save(&self) {
+        let path = utils::get_save_path(WALLET_FILE_NAME);
+        let save = WalletSave {
+            coins: self.total_coins,
+        };
+        let save = serde_json::to_string(&save).expect("Failed to serialise the wallet");
+
+        if let Err(error) = fs::write(&path, save) {
+            warn!("Failed to save the wallet to {}: {error}", path.display());
+        }
+    }
+}
+
+fn finish_run(mut wallet: ResMut<Wallet>) {
+    info!(
+        "Coins collected: {}, {} in the wallet",
+        wallet.run_coins, wallet.total_coins
+    );
+
+    if wallet.run_coins > 0 {
+        wallet.save();
+    }
+
+    wallet.run_coins = 0;
+}
+
+fn save_on_exit(mut exit_events: EventReader<AppExit>, wallet: Res<Wallet>) {
+    if exit_events.read().last().is_some() && wallet.run_coins > 0 {
+        wallet.save();
+    }
+}
+
+/// Collected coins fly up and shrink, but stay in their chunk until it's despawned.
+fn pick_up_coins(
+    mut commands: Commands,
+    mut coin_pickup_requests: EventReader<RequestCoinPickup>,
+    mut wallet: ResMut<Wallet>,
+    coins: Query<&Transform, With<Coin>>,
+) {
+    for request in coin_pickup_requests.read() {
+        let Ok(transform) = coins.get(request.get()) else {
+            continue;
+        };
+
+        let tracks = Tracks::new([
+            Tween::new(
+                EaseFunction::QuadraticOut,
+                COIN_PICKUP_DURATION,
+                TransformPositionLens {
+                    start: transform.translation,
+                    end: transform.translation + Vec3::Y * COIN_PICKUP_HEIGHT,
+                },
+            ),
+            Tween::new(
+                EaseFunction::QuadraticIn,
+                COIN_PICKUP_DURATION,
+                TransformScaleLens {
+                    start: transform.scale,
+                    end: Vec3::ZERO,
+                },
+            ),
+        ]);
+
+        commands.entity(request.get()).insert(Animator::new(tracks));
+
+        wallet.run_coins += 1;
+        wallet.total_coins += 1;
+    }
+}
+
+fn spin_coins(time: Res<Time>, mut coins: Query<&mut Transform, With<Coin>>) {
+    for mut transform in coins.iter_mut() {
+        transform.rotate_y(COIN_SPIN_SPEED * time.delta_seconds());
+    }
+}
@@ -6,6 +6,7 @@ use bevy::prelude::KeyCode;
 // Camera
 pub const CAMERA_MOVEMENT_SPEED: Duration = Duration::from_secs(1);
 pub const CAMERA_SPAWN_POINT: Vec3 = Vec3::new(-1.5, 3., 0.75);
+pub const CAMERA_CREEP_SPEED: f32 = 0.4;
 
 // Map
 pub const MAP_MIN_X: i32 = -8;
@@ -13,20 +14,67 @@ pub const MAP_MIN_Z: i32 = -10;
 pub const MAP_MAX_Z: i32 = 10;
 pub const MAP_GAMEPLAY_MIN_Z: i32 = -4;
 pub const MAP_GAMEPLAY_MAX_Z: i32 = 4;
+pub const MAP_LOOK_AHEAD_DISTANCE: i32 = 12;
+pub const MAP_LOOK_BEHIND_DISTANCE: i32 = 8;
+
+// Map generator
+pub const MAPGEN_DEFAULT_CHUNK_COUNT: usize = 32;
+pub const MAPGEN_MAX_UPDATES: usize = 100;
+
+// Difficulty
+pub const DIFFICULTY_RAMP_DISTANCE: i32 = 300;
+
+// Biomes
+pub const BIOME_TRANSITION_DISTANCE: i32 = 120;
+pub const BIOME_TRANSITION_STRIP_LENGTH: i32 = 3;
 
 // Characters scaling
 pub const FLATTEN_SCALE: Vec3 = Vec3::new(1.125, 0.875, 1.125);
 
 // Player
 pub const PLAYER_SPAWN_POINT: Vec3 = Vec3::new(0., 1.01, 0.);
+pub const PLAYER_HITBOX_HALF_SIZE: f32 = 0.35;
 pub const PLAYER_ANIMATION_DURATION: Duration = Duration::from_millis(200);
 pub const PLAYER_MAX_JUMP_QUEUE: usize = 2;
+pub const PLAYER_MAX_BACK_DISTANCE: i32 = 4;
 pub const PLAYER_JUMP_HEIGHT: f32 = 0.25;
+pub const PLAYER_SLIDE_HEIGHT: f32 = 0.05;
 pub const PLAYER_MOVE_FORWARD_KEY_CODES: [KeyCode; 3] =
     [KeyCode::KeyW, KeyCode::ArrowUp, KeyCode::Space];
 pub const PLAYER_MOVE_BACK_KEY_CODES: [KeyCode; 2] = [KeyCode::KeyS, KeyCode::ArrowDown];
 pub const PLAYER_MOVE_LEFT_KEY_CODES: [KeyCode; 2] = [KeyCode::KeyA, KeyCode::ArrowLeft];
 pub const PLAYER_MOVE_RIGHT_KEY_CODES: [KeyCode; 2] = [KeyCode::KeyD, KeyCode::ArrowRight];
 
+// Eagle
+pub const EAGLE_IDLE_TIMEOUT: Duration = Duration::from_secs(7);
+pub const EAGLE_BEHIND_CAMERA_DISTANCE: f32 = 3.;
+pub const EAGLE_SWOOP_START: Vec3 = Vec3::new(8., 4., 0.);
+pub const EAGLE_SWOOP_DURATION: Duration = Duration::from_millis(600);
+pub const EAGLE_CARRY_DURATION: Duration = Duration::from_secs(1);
+pub const EAGLE_CARRY_VELOCITY: Vec3 = Vec3::new(-10., 4., 0.);
+
+// Vehicles
+pub const VEHICLE_MIN_SPEED: f32 = 1.5;
+pub const VEHICLE_MAX_SPEED: f32 = 4.;
+
+// Logs
+pub const LOG_MIN_SPEED: f32 = 0.75;
+pub const LOG_MAX_SPEED: f32 = 2.;
+
+// Railways
+pub const RAILWAY_MIN_IDLE_DURATION: Duration = Duration::from_secs(3);
+pub const RAILWAY_MAX_IDLE_DURATION: Duration = Duration::from_secs(7);
+pub const RAILWAY_WARNING_DURATION: Duration = Duration::from_millis(1_500);
+pub const RAILWAY_SIGNAL_BLINK_INTERVAL: Duration = Duration::from_millis(250);
+pub const TRAIN_SPEED: f32 = 25.;
+
+// Coins
+pub const COIN_SPIN_SPEED: f32 = 2.;
+pub const COIN_PICKUP_DURATION: Duration = Duration::from_millis(300);
+pub const COIN_PICKUP_HEIGHT: f32 = 1.;
+
+// Saves
+pub const WALLET_FILE_NAME: &str = "wallet.json";
+
 // Other
 pub const GLOBAL_GRAVITY: f32 = 40.;
@@ -16,9 +16,12 @@ use bevy_inspector_egui::egui::{Button, CollapsingHeader, ComboBox, ScrollArea,
 use bevy_inspector_egui::DefaultInspectorConfigPlugin;
 use bevy_rapier3d::render::{DebugRenderContext, RapierDebugRenderPlugin};
 
+use crate::coins::Wallet;
 use crate::events::DevRequestBiome;
-use crate::player::Player;
+use crate::player::{Player, RunProgress};
 use crate::states::CurrentBiome;
+use crate::world::difficulty::Difficulty;
+use crate::world::pool::EntityPool;
 use crate::world::Map;
 
 const GAP_BETWEEN_SECTIONS: f32 = 4.;
@@ -133,12 +136,50 @@ fn update_ui(
             ui.add_space(GAP_BETWEEN_SECTIONS);
 
             ui.collapsing("World", |ui| {
-                let chunks = match world.get_resource_ref::<Map>() {
-                    Some(map) => map.chunks.len().to_string(),
-                    None => "no map".into(),
+                let (seed, chunks) = match world.get_resource_ref::<Map>() {
+                    Some(map) => (map.seed.to_string(), map.chunks.len().to_string()),
+                    None => ("no map".into(), "no map".into()),
                 };
+                ui.label(format!("Seed: {}", seed));
                 ui.label(format!("Chunks Spawned: {}", chunks));
 
+                let pool = match world.get_resource_ref::<EntityPool>() {
+                    Some(pool) => format!(
+                        "{} hits, {} misses, {} idle",
+                        pool.hits,
+                        pool.misses,
+                        pool.idle_count()
+                    ),
+                    None => "no pool".into(),
+                };
+                ui.label(format!("Entity Pool: {}", pool));
+
+                let coins = match world.get_resource_ref::<Wallet>() {
+                    Some(wallet) => format!(
+                        "{} this run, {} in the wallet",
+                        wallet.run_coins, wallet.total_coins
+                    ),
+                    None => "no wallet".into(),
+                };
+                ui.label(format!("Coins: {}", coins));
+
+                let furthest_x = world.resource::<RunProgress>().furthest_x;
+                let difficulty = match world.get_resource_ref::<Difficulty>() {
+                    Some(difficulty) => {
+                        let params = difficulty.at(furthest_x);
+
+                        format!(
+                            "{:?}, {:.2}x obstacles, {:.2}x hazards, {:.2}x speed",
+                            difficulty.mode,
+                            params.obstacle_density,
+                            params.hazard_chance,
+                            params.vehicle_speed
+                        )
+                    }
+                    None => "no difficulty".into(),
+                };
+                ui.label(format!("Difficulty: {}", difficulty));
+
                 ui.horizontal(|ui| {
                     ui.label("Current Biome");
 
@@ -1,5 +1,6 @@
-use bevy::prelude::Event;
+use bevy::prelude::{Entity, Event};
 
+use crate::player::PlayerDeathCause;
 #[cfg(feature = "debug")]
 use crate::states::CurrentBiome;
 
@@ -23,3 +24,29 @@ pub struct RequestNewChunkSpawning;
 
 #[derive(Event)]
 pub struct RequestOldChunkDespawning;
+
+#[derive(Event)]
+pub struct RequestPlayerDeath(PlayerDeathCause);
+
+impl RequestPlayerDeath {
+    pub fn new(cause: PlayerDeathCause) -> Self {
+        Self(cause)
+    }
+
+    pub fn get(&self) -> PlayerDeathCause {
+        self.0
+    }
+}
+
+#[derive(Event)]
+pub struct RequestCoinPickup(Entity);
+
+impl RequestCoinPickup {
+    pub fn new(coin: Entity) -> Self {
+        Self(coin)
+    }
+
+    pub fn get(&self) -> Entity {
+        self.0
+    }
+}
@@ -1,6 +1,11 @@
-use bevy::app::{App, Plugin};
-use bevy::prelude::{in_state, IntoSystemConfigs, NextState, OnEnter, ResMut};
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::{in_state, EventReader, IntoSystemConfigs, NextState, OnEnter, ResMut};
+#[cfg(feature = "debug")]
+use bevy::prelude::{EventWriter, Res, State};
 
+#[cfg(feature = "debug")]
+use crate::events::DevRequestBiome;
+use crate::events::RequestPlayerDeath;
 use crate::states::{AppState, CurrentBiome};
 
 pub struct LifecyclePlugin;
@@ -11,7 +16,11 @@ impl Plugin for LifecyclePlugin {
             OnEnter(CurrentBiome::None),
             handle_none_biome.run_if(in_state(AppState::Playing)),
         )
-        .add_systems(OnEnter(AppState::Clearing), clear);
+        .add_systems(OnEnter(AppState::Clearing), clear)
+        .add_systems(
+            Update,
+            handle_player_death.run_if(in_state(AppState::Playing)),
+        );
     }
 }
 
@@ -22,3 +31,24 @@ fn handle_none_biome(mut app_state: ResMut<NextState<AppState>>) {
 fn clear(mut app_state: ResMut<NextState<AppState>>) {
     app_state.set(AppState::InitialisingWorld);
 }
+
+/// The level is restarted by leaving the biome for [CurrentBiome::None],
+/// and then the world is cleared while [AppState::Clearing].
+fn handle_player_death(
+    #[cfg(feature = "debug")] mut biome_dev_requester: EventWriter<DevRequestBiome>,
+    mut player_death_requests: EventReader<RequestPlayerDeath>,
+    mut current_biome_setter: ResMut<NextState<CurrentBiome>>,
+    #[cfg(feature = "debug")] current_biome: Res<State<CurrentBiome>>,
+) {
+    let Some(request) = player_death_requests.read().last() else {
+        return;
+    };
+
+    info!("Player died: {:?}", request.get());
+
+    // Keeps the biome selected from the development menu
+    #[cfg(feature = "debug")]
+    biome_dev_requester.send(DevRequestBiome::new(*current_biome.get()));
+
+    current_biome_setter.set(CurrentBiome::None);
+}
@@ -13,11 +13,14 @@ use bevy_tweening::TweeningPlugin;
 use dotenv::dotenv;
 
 use crate::camera::CameraPlugin;
+use crate::coins::CoinPlugin;
 #[cfg(feature = "debug")]
 use crate::dev::DevelopmentPlugin;
 #[cfg(feature = "debug")]
 use crate::events::DevRequestBiome;
-use crate::events::{RequestNewChunkSpawning, RequestOldChunkDespawning};
+use crate::events::{
+    RequestCoinPickup, RequestNewChunkSpawning, RequestOldChunkDespawning, RequestPlayerDeath,
+};
 use crate::lifecycle::LifecyclePlugin;
 use crate::player::PlayerPlugin;
 use crate::resources::ResourcePlugin;
@@ -25,11 +28,13 @@ use crate::states::{AppState, CurrentBiome};
 use crate::world::WorldPlugin;
 
 mod camera;
+mod coins;
 mod constants;
 #[cfg(feature = "debug")]
 mod dev;
 mod events;
 mod lifecycle;
+mod mapgen;
 mod player;
 mod resources;
 mod states;
@@ -40,6 +45,10 @@ fn main() {
     dotenv().ok();
     env_logger::init();
 
+    if mapgen::is_requested() {
+        return mapgen::run();
+    }
+
     info!("Starting Crossy Road");
 
     let mut app = App::new();
@@ -67,17 +76,7 @@ fn main() {
     ));
 
     // Current crate
-    app.add_event::<RequestNewChunkSpawning>()
-        .add_event::<RequestOldChunkDespawning>()
-        .init_state::<AppState>()
-        .init_state::<CurrentBiome>()
-        .add_plugins((
-            CameraPlugin,
-            LifecyclePlugin,
-            PlayerPlugin,
-            ResourcePlugin,
-            WorldPlugin,
-        ));
+    add_game(&mut app);
 
     // For development
     #[cfg(feature = "debug")]
@@ -86,3 +85,25 @@ fn main() {
 
     app.run()
 }
+
+/// Everything from the current crate.
+fn add_game(app: &mut App) {
+    add_world(app, ResourcePlugin::default());
+
+    app.add_event::<RequestCoinPickup>().add_plugins((
+        CameraPlugin,
+        CoinPlugin,
+        LifecyclePlugin,
+        PlayerPlugin,
+    ));
+}
+
+/// What generating the world needs, it also works without a window or a renderer.
+fn add_world(app: &mut App, resource_plugin: ResourcePlugin) {
+    app.add_event::<RequestNewChunkSpawning>()
+        .add_event::<RequestOldChunkDespawning>()
+        .add_event::<RequestPlayerDeath>()
+        .init_state::<AppState>()
+        .init_state::<CurrentBiome>()
+        .add_plugins((resource_plugin, WorldPlugin));
+}
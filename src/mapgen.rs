@@ -0,0 +1,208 @@
+//! Generates the world without a window or a renderer and prints its tile grid,
+//! so layouts can be reviewed and diffed without playing.
+//!
+//! Usage: `crossy-road-rs mapgen [--seed 42] [--biome winter] [--chunks 40] [--format json]`
+
+use std::env;
+use std::process;
+
+use bevy::app::App;
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Mesh, OnEnter, State, Transform};
+use bevy::MinimalPlugins;
+use serde::Serialize;
+
+use crate::camera::Camera;
+use crate::constants::{MAPGEN_DEFAULT_CHUNK_COUNT, MAPGEN_MAX_UPDATES, PLAYER_SPAWN_POINT};
+#[cfg(feature = "debug")]
+use crate::events::DevRequestBiome;
+use crate::player::{CurrentCharacter, RunProgress};
+use crate::resources::biomes::ObstacleKind;
+use crate::resources::ResourcePlugin;
+use crate::states::AppState;
+use crate::utils;
+use crate::world::tiles::{Tile, TileKind};
+use crate::world::{ChunkStreaming, Map, MapConfig, RequestedRun};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MapFormat {
+    Ascii,
+    Json,
+}
+
+/// The map generator is run with `mapgen` as the first command line argument.
+pub fn is_requested() -> bool {
+    env::args()
+        .nth(1)
+        .is_some_and(|argument| argument == "mapgen")
+}
+
+/// Prints the generated map to the standard output, logs still go to the standard error.
+pub fn run() {
+    let chunk_count = match utils::get_argument("chunks") {
+        Some(count) => count.trim().parse().unwrap_or_else(|_| {
+            error!("Invalid chunk count: {count}");
+            process::exit(2);
+        }),
+        None => MAPGEN_DEFAULT_CHUNK_COUNT,
+    };
+    let format = match utils::get_argument("format").as_deref() {
+        None | Some("ascii") => MapFormat::Ascii,
+        Some("json") => MapFormat::Json,
+        Some(format) => {
+            error!("Unknown map format: {format}");
+            process::exit(2);
+        }
+    };
+
+    let Some(map) = generate(
+        RequestedRun::from_settings(),
+        MapConfig::default(),
+        chunk_count,
+    ) else {
+        error!("The map wasn't generated in {MAPGEN_MAX_UPDATES} updates");
+        process::exit(1);
+    };
+
+    match format {
+        MapFormat::Ascii => print!("{}", to_ascii(&map, chunk_count)),
+        MapFormat::Json => println!("{}", to_json(&map, chunk_count)),
+    }
+}
+
+/// Runs the world in a minimal app until the first `chunk_count` chunks are spawned.
+/// Models are never loaded, so that always takes the same number of updates.
+/// Returns `None` if the chunks aren't spawned within [MAPGEN_MAX_UPDATES] updates.
+pub fn generate(
+    requested_run: RequestedRun,
+    map_config: MapConfig,
+    chunk_count: usize,
+) -> Option<Map> {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>();
+    crate::add_world(&mut app, ResourcePlugin { is_headless: true });
+
+    #[cfg(feature = "debug")]
+    app.add_event::<DevRequestBiome>();
+
+    // All chunks are spawned right away and nothing is despawned behind the player
+    let last_x = map_config.min_x + chunk_count as i32 - 1;
+    app.insert_resource(requested_run)
+        .insert_resource(map_config)
+        .insert_resource(ChunkStreaming {
+            look_ahead: (last_x - PLAYER_SPAWN_POINT.x as i32).max(1),
+            look_behind: chunk_count as i32,
+        })
+        .init_resource::<RunProgress>()
+        .add_systems(
+            OnEnter(AppState::InsertingCurrentCharacter),
+            CurrentCharacter::insert_resource,
+        );
+
+    // There is no player, chunks after a biome transition are streamed around the spawn point
+    app.world.spawn((
+        Camera::default(),
+        Transform::from_translation(PLAYER_SPAWN_POINT),
+    ));
+
+    for _ in 0..MAPGEN_MAX_UPDATES {
+        app.update();
+
+        let is_playing = app
+            .world
+            .resource::<State<AppState>>()
+            .get()
+            .eq(&AppState::Playing);
+        let map = app.world.resource::<Map>();
+
+        if is_playing && map.next_chunk_x() > last_x {
+            return Some(map.clone());
+        }
+    }
+
+    None
+}
+
+/// One line per chunk, the furthest chunk is at the top like on the screen.
+pub fn to_ascii(map: &Map, chunk_count: usize) -> String {
+    let mut ascii = String::new();
+
+    for chunk in map.chunks.iter().take(chunk_count).rev() {
+        let row = map
+            .tiles
+            .tiles_in_row(chunk.position_x)
+            .map(|(_, tile)| tile_symbol(tile))
+            .collect::<String>();
+
+        ascii.push_str(&format!("{:>5} {}\n", chunk.position_x, row));
+    }
+
+    ascii
+}
+
+pub fn to_json(map: &Map, chunk_count: usize) -> String {
+    let generated_map = GeneratedMap {
+        seed: map.seed,
+        min_z: map.config.min_z,
+        max_z: map.config.max_z,
+        chunks: map
+            .chunks
+            .iter()
+            .take(chunk_count)
+            .map(|chunk| GeneratedChunk {
+                x: chunk.position_x,
+                biome: format!("{:?}", chunk.biome),
+                tiles: map
+                    .tiles
+                    .tiles_in_row(chunk.position_x)
+                    .map(|(_, tile)| GeneratedTile {
+                        kind: tile.kind,
+                        obstacle: tile.obstacle.map(|obstacle| obstacle.kind),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&generated_map).expect("Failed to serialise the map")
+}
+
+/// Obstacles are shown instead of the ground under them.
+fn tile_symbol(tile: &Tile) -> char {
+    match (tile.obstacle.map(|obstacle| obstacle.kind), tile.kind) {
+        (Some(ObstacleKind::Tree), _) => 'T',
+        (Some(ObstacleKind::Boulder), _) => 'B',
+        (Some(ObstacleKind::Stump), _) => 'S',
+        (Some(ObstacleKind::Empty) | None, TileKind::Ground) => '.',
+        (Some(ObstacleKind::Empty) | None, TileKind::Ice) => '_',
+        (Some(ObstacleKind::Empty) | None, TileKind::Road) => '=',
+        (Some(ObstacleKind::Empty) | None, TileKind::Rail) => '#',
+        (Some(ObstacleKind::Empty) | None, TileKind::Water) => '~',
+    }
+}
+
+#[derive(Serialize)]
+struct GeneratedMap {
+    seed: u64,
+    /// Tiles of every chunk start at this z.
+    min_z: i32,
+    max_z: i32,
+    chunks: Vec<GeneratedChunk>,
+}
+
+#[derive(Serialize)]
+struct GeneratedChunk {
+    x: i32,
+    biome: String,
+    tiles: Vec<GeneratedTile>,
+}
+
+#[derive(Serialize)]
+struct GeneratedTile {
+    kind: TileKind,
+    obstacle: Option<ObstacleKind>,
+}
@@ -0,0 +1,219 @@
+use std::time::Duration;
+
+use bevy::app::{App, Plugin, Update};
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::math::Vec3;
+use bevy::pbr::PbrBundle;
+use bevy::prelude::{
+    in_state, Commands, Component, Entity, EventWriter, IntoSystemConfigs, OnEnter, Query, Res,
+    Resource, Transform, With, Without,
+};
+use bevy::time::Time;
+use bevy_rapier3d::control::KinematicCharacterController;
+
+use crate::camera::Camera;
+use crate::constants::{
+    EAGLE_BEHIND_CAMERA_DISTANCE, EAGLE_CARRY_DURATION, EAGLE_CARRY_VELOCITY, EAGLE_IDLE_TIMEOUT,
+    EAGLE_SWOOP_DURATION, EAGLE_SWOOP_START,
+};
+use crate::events::RequestPlayerDeath;
+use crate::player::{Player, PlayerDeathCause};
+use crate::resources::eagles::EagleCollection;
+use crate::states::AppState;
+
+pub(super) struct EaglePlugin;
+
+impl Plugin for EaglePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EagleTrigger>()
+            .add_systems(OnEnter(AppState::Clearing), despawn)
+            .add_systems(
+                Update,
+                (summon_eagle, swoop)
+                    .chain()
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
+
+/// When the eagle comes for the player.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct EagleTrigger {
+    /// How long the player may stand still.
+    pub idle_timeout: Duration,
+    /// How many cells the player may fall behind the point the camera looks at.
+    pub behind_camera_distance: f32,
+}
+
+impl EagleTrigger {
+    pub fn is_idle_too_long(&self, idle_time: Duration) -> bool {
+        idle_time >= self.idle_timeout
+    }
+
+    pub fn is_behind_camera(&self, player_x: f32, camera_focus_x: f32) -> bool {
+        player_x < camera_focus_x - self.behind_camera_distance
+    }
+}
+
+impl Default for EagleTrigger {
+    fn default() -> Self {
+        Self {
+            idle_timeout: EAGLE_IDLE_TIMEOUT,
+            behind_camera_distance: EAGLE_BEHIND_CAMERA_DISTANCE,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EaglePhase {
+    /// Flies down to the player, who can still hop around.
+    Swooping,
+    /// Flies away with the player, who has lost control.
+    CarryingOff,
+    /// The run is over, the eagle only waits to be despawned.
+    Finished,
+}
+
+#[derive(Component)]
+pub struct Eagle {
+    phase: EaglePhase,
+    /// When the current phase started, in seconds since the app start.
+    phase_started_at: f32,
+    start_translation: Vec3,
+}
+
+fn despawn(mut commands: Commands, eagles: Query<Entity, With<Eagle>>) {
+    for entity in eagles.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn summon_eagle(
+    mut commands: Commands,
+    time: Res<Time>,
+    trigger: Res<EagleTrigger>,
+    eagles: Res<EagleCollection>,
+    mut players: Query<(&mut Player, &Transform)>,
+    cameras: Query<&Camera>,
+    summoned_eagles: Query<(), With<Eagle>>,
+) {
+    let Some((mut player, transform)) = players.iter_mut().next() else {
+        return;
+    };
+
+    if !summoned_eagles.is_empty() {
+        return;
+    }
+
+    player.idle_time = match player.is_grounded {
+        true => player.idle_time + time.delta(),
+        false => Duration::ZERO,
+    };
+
+    let is_behind_camera = cameras
+        .iter()
+        .next()
+        .is_some_and(|camera| trigger.is_behind_camera(transform.translation.x, camera.focus_x));
+
+    if !trigger.is_idle_too_long(player.idle_time) && !is_behind_camera {
+        return;
+    }
+
+    info!(
+        "Summoning the eagle, idle for {:?}, behind the camera: {}",
+        player.idle_time, is_behind_camera
+    );
+
+    let start_translation = transform.translation + EAGLE_SWOOP_START;
+    let model = &eagles.eagle;
+
+    commands.spawn((
+        PbrBundle {
+            mesh: model.mesh.clone_weak(),
+            material: model.material.clone_weak(),
+            transform: Transform::from_translation(start_translation),
+            ..Default::default()
+        },
+        Eagle {
+            phase: EaglePhase::Swooping,
+            phase_started_at: time.elapsed_seconds(),
+            start_translation,
+        },
+    ));
+}
+
+/// The eagle catches up with the player wherever they hop,
+/// so the swoop always ends with the player being carried off.
+fn swoop(
+    mut commands: Commands,
+    mut player_death_requester: EventWriter<RequestPlayerDeath>,
+    time: Res<Time>,
+    mut eagles: Query<(&mut Eagle, &mut Transform), Without<Player>>,
+    mut players: Query<(Entity, &mut Transform), With<Player>>,
+) {
+    let Some((mut eagle, mut eagle_transform)) = eagles.iter_mut().next() else {
+        return;
+    };
+    let Some((player_entity, mut player_transform)) = players.iter_mut().next() else {
+        return;
+    };
+
+    let phase_time = time.elapsed_seconds() - eagle.phase_started_at;
+    let grip_offset = Vec3::Y * 0.5;
+
+    match eagle.phase {
+        EaglePhase::Swooping => {
+            let progress = (phase_time / EAGLE_SWOOP_DURATION.as_secs_f32()).min(1.);
+
+            eagle_transform.translation = eagle
+                .start_translation
+                .lerp(player_transform.translation + grip_offset, progress);
+
+            if progress >= 1. {
+                // Without the controller, the player systems can't move the player anymore
+                commands
+                    .entity(player_entity)
+                    .remove::<KinematicCharacterController>();
+
+                eagle.phase = EaglePhase::CarryingOff;
+                eagle.phase_started_at = time.elapsed_seconds();
+            }
+        }
+        EaglePhase::CarryingOff => {
+            eagle_transform.translation += EAGLE_CARRY_VELOCITY * time.delta_seconds();
+            player_transform.translation = eagle_transform.translation - grip_offset;
+
+            if phase_time >= EAGLE_CARRY_DURATION.as_secs_f32() {
+                player_death_requester.send(RequestPlayerDeath::new(PlayerDeathCause::Eagle));
+                eagle.phase = EaglePhase::Finished;
+            }
+        }
+        EaglePhase::Finished => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::EagleTrigger;
+
+    #[test]
+    fn eagle_comes_after_the_idle_timeout() {
+        let trigger = EagleTrigger::default();
+
+        assert!(!trigger.is_idle_too_long(Duration::ZERO));
+        assert!(!trigger.is_idle_too_long(Duration::from_millis(6_999)));
+        assert!(trigger.is_idle_too_long(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn eagle_comes_once_the_player_is_too_far_behind_the_camera() {
+        let trigger = EagleTrigger::default();
+
+        assert!(!trigger.is_behind_camera(10., 10.));
+        assert!(!trigger.is_behind_camera(12., 10.));
+        assert!(!trigger.is_behind_camera(7., 10.));
+        assert!(trigger.is_behind_camera(6.5, 10.));
+    }
+}
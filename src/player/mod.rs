@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use std::f32::consts::{FRAC_PI_2, PI, TAU};
+use std::time::Duration;
 
 use bevy::app::{App, Plugin, Update};
 use bevy::hierarchy::{BuildChildren, Children, DespawnRecursiveExt};
@@ -7,8 +8,8 @@ use bevy::input::ButtonInput;
 use bevy::math::{IVec2, Quat, Vec3};
 use bevy::pbr::PbrBundle;
 use bevy::prelude::{
-    in_state, Commands, Component, Entity, KeyCode, NextState, OnEnter, Query, Res, ResMut,
-    Resource, State, Transform, Visibility, With,
+    in_state, Commands, Component, Entity, EventWriter, KeyCode, NextState, OnEnter, Query, Res,
+    ResMut, Resource, State, Transform, Visibility, With,
 };
 use bevy::prelude::{IntoSystemConfigs, SpatialBundle};
 use bevy::time::Time;
@@ -21,37 +22,49 @@ use bevy_tweening::lens::{TransformPositionLens, TransformScaleLens};
 use bevy_tweening::{Animator, AnimatorState, EaseFunction, Sequence, Tracks, Tween};
 
 use crate::constants::{
-    FLATTEN_SCALE, GLOBAL_GRAVITY, MAP_GAMEPLAY_MAX_Z, MAP_GAMEPLAY_MIN_Z,
-    PLAYER_ANIMATION_DURATION, PLAYER_JUMP_HEIGHT, PLAYER_MAX_JUMP_QUEUE,
+    FLATTEN_SCALE, PLAYER_ANIMATION_DURATION, PLAYER_MAX_BACK_DISTANCE, PLAYER_MAX_JUMP_QUEUE,
     PLAYER_MOVE_BACK_KEY_CODES, PLAYER_MOVE_FORWARD_KEY_CODES, PLAYER_MOVE_LEFT_KEY_CODES,
     PLAYER_MOVE_RIGHT_KEY_CODES, PLAYER_SPAWN_POINT,
 };
+use crate::events::{RequestCoinPickup, RequestPlayerDeath};
+use crate::player::eagle::EaglePlugin;
+use crate::resources::biomes::PhysicsProfile;
 use crate::resources::characters::{Character, CharacterCollection};
 use crate::states::AppState;
 use crate::utils;
+use crate::world::carriers::{Carrier, CarrierSet};
 use crate::world::Map;
 
+pub mod eagle;
+
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(AppState::InsertingCurrentCharacter),
-            CurrentCharacter::insert_resource,
-        )
-        .add_systems(OnEnter(AppState::Playing), spawn)
-        .add_systems(OnEnter(AppState::Clearing), despawn)
-        .add_systems(
-            Update,
-            (
-                move_player,
-                handle_move_keys,
-                init_player_move,
-                flatten_player,
+        app.add_plugins(EaglePlugin)
+            .add_systems(
+                OnEnter(AppState::InsertingCurrentCharacter),
+                CurrentCharacter::insert_resource,
             )
-                .chain()
-                .run_if(in_state(AppState::Playing)),
-        );
+            .init_resource::<RunProgress>()
+            .add_systems(OnEnter(AppState::Playing), (spawn, RunProgress::reset))
+            .add_systems(OnEnter(AppState::Clearing), despawn)
+            .add_systems(
+                Update,
+                (
+                    move_player,
+                    track_progress,
+                    handle_player_landing,
+                    carry_player,
+                    handle_move_keys,
+                    init_player_move,
+                    flatten_player,
+                )
+                    .chain()
+                    // Landings must see the platform cells of the current frame
+                    .after(CarrierSet)
+                    .run_if(in_state(AppState::Playing)),
+            );
     }
 }
 
@@ -67,18 +80,62 @@ impl CurrentCharacter {
         &self.0
     }
 
-    fn insert_resource(
+    pub fn insert_resource(
         mut commands: Commands,
         mut app_state_setter: ResMut<NextState<AppState>>,
         app_state: Res<State<AppState>>,
         characters: Res<CharacterCollection>,
     ) {
-        commands.insert_resource(Self::new(characters.chicken.clone()));
+        let character = match utils::get_requested_character() {
+            Some(name) => characters.get(&name).unwrap_or_else(|| {
+                warn!("Ignoring unknown character: {name}");
+                &characters.chicken
+            }),
+            None => &characters.chicken,
+        };
+
+        info!("Character: {}", character.name);
+
+        commands.insert_resource(Self::new(character.clone()));
         app_state_setter.set(app_state.get().next());
     }
 }
 
-#[derive(Default, PartialEq)]
+/// How far the player has got during the current run.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct RunProgress {
+    pub furthest_x: i32,
+}
+
+impl Default for RunProgress {
+    fn default() -> Self {
+        Self {
+            furthest_x: PLAYER_SPAWN_POINT.x as i32,
+        }
+    }
+}
+
+impl RunProgress {
+    /// The player can't go back further than this row, so chunks from it onwards are kept.
+    pub fn min_reachable_x(&self) -> i32 {
+        self.furthest_x - PLAYER_MAX_BACK_DISTANCE
+    }
+
+    fn reset(mut progress: ResMut<Self>) {
+        *progress = Self::default();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlayerDeathCause {
+    Vehicle,
+    Train,
+    Drowning,
+    OutOfBounds,
+    Eagle,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum PlayerJumpDirection {
     #[default]
     Forward,
@@ -91,8 +148,22 @@ pub enum PlayerJumpDirection {
 pub struct Player {
     jump_queue: VecDeque<PlayerJumpDirection>,
     velocity: Vec3,
+    /// Gravity of the current hop. It's only taken from the physics profile when a hop starts,
+    /// so the player always lands on the planned cell, even if the biome changes mid-air.
+    gravity: f32,
     is_grounded: bool,
     is_initial_jump_made: bool,
+    is_just_landed: bool,
+    last_jump_direction: PlayerJumpDirection,
+    /// Follow-up move caused by the ground the player has landed on,
+    /// which is made before the next jump from the queue
+    slide_direction: Option<PlayerJumpDirection>,
+    /// The current move was caused by the ground, not by the jump queue
+    is_sliding: bool,
+    /// Moving platform the player is standing on, or hopping along
+    carrier: Option<Entity>,
+    /// Time spent on the ground since the last hop, the eagle comes once it's too long
+    idle_time: Duration,
 }
 
 #[derive(Default, Component)]
@@ -103,8 +174,13 @@ pub struct PlayerModel {
     pub end_rotation: Option<f32>,
 }
 
-fn spawn(mut commands: Commands, characters: Res<CharacterCollection>) {
-    let child_translation = Vec3::new(0., -0.5 + characters.chicken.model.mesh_size.y / 2., 0.);
+fn spawn(
+    mut commands: Commands,
+    current_character: Res<CurrentCharacter>,
+    physics: Res<PhysicsProfile>,
+) {
+    let model = &current_character.get().model;
+    let child_translation = Vec3::new(0., -0.5 + model.mesh_size.y / 2., 0.);
     let child_animator = Animator::new(Tween::new(
         EaseFunction::CubicInOut,
         PLAYER_ANIMATION_DURATION,
@@ -116,7 +192,10 @@ fn spawn(mut commands: Commands, characters: Res<CharacterCollection>) {
 
     commands
         .spawn((
-            Player::default(),
+            Player {
+                gravity: physics.gravity,
+                ..Default::default()
+            },
             SpatialBundle::from_transform(Transform::from_translation(PLAYER_SPAWN_POINT)),
             RigidBody::KinematicPositionBased,
             Collider::cuboid(0.5, 0.5, 0.5),
@@ -130,8 +209,8 @@ fn spawn(mut commands: Commands, characters: Res<CharacterCollection>) {
         .with_children(|builder| {
             builder.spawn((
                 PbrBundle {
-                    mesh: characters.chicken.model.mesh.clone(),
-                    material: characters.chicken.model.material.clone(),
+                    mesh: model.mesh.clone(),
+                    material: model.material.clone(),
                     transform: Transform::from_translation(child_translation),
                     visibility: Visibility::Visible,
                     ..Default::default()
@@ -165,12 +244,19 @@ fn move_player(
     };
 
     if player.velocity.y > -50. {
-        player.velocity.y -= GLOBAL_GRAVITY * time.delta_seconds();
+        player.velocity.y -= player.gravity * time.delta_seconds();
     }
 
     if let Some(controller_output) = player_controller_outputs.iter().next() {
         if player.is_initial_jump_made && !controller_output.collisions.is_empty() {
-            player.jump_queue.pop_front();
+            if !player.is_grounded {
+                player.is_just_landed = true;
+            }
+
+            if !player.is_sliding {
+                player.jump_queue.pop_front();
+            }
+
             player.velocity = Vec3::ZERO;
             player.is_grounded = true;
         }
@@ -206,8 +292,100 @@ fn move_player(
     }
 }
 
+fn handle_player_landing(
+    mut player_death_requester: EventWriter<RequestPlayerDeath>,
+    mut coin_pickup_requester: EventWriter<RequestCoinPickup>,
+    mut map: ResMut<Map>,
+    mut players: Query<(&mut Player, &Transform)>,
+) {
+    let Some((mut player, transform)) = players.iter_mut().next() else {
+        return;
+    };
+
+    if !player.is_just_landed {
+        return;
+    }
+
+    player.is_just_landed = false;
+    player.carrier = None;
+
+    let was_sliding = player.is_sliding;
+    player.is_sliding = false;
+
+    let cell = IVec2::new(
+        transform.translation.x.round() as i32,
+        transform.translation.z.round() as i32,
+    );
+
+    let Some(tile) = map.tiles.get_mut(cell.x, cell.y) else {
+        return;
+    };
+
+    if let Some(coin) = tile.coin.take() {
+        coin_pickup_requester.send(RequestCoinPickup::new(coin));
+    }
+
+    match tile.platform {
+        Some(carrier) => {
+            player.carrier = Some(carrier);
+        }
+        None if tile.is_lethal() => {
+            player_death_requester.send(RequestPlayerDeath::new(PlayerDeathCause::Drowning));
+        }
+        // Ice only makes the player slide one cell, even if there is more ice after it
+        None if tile.kind.is_slippery() && !was_sliding => {
+            player.slide_direction = Some(player.last_jump_direction);
+        }
+        None => {}
+    }
+}
+
+fn track_progress(mut progress: ResMut<RunProgress>, players: Query<&Transform, With<Player>>) {
+    let Some(transform) = players.iter().next() else {
+        return;
+    };
+    let x = transform.translation.x.round() as i32;
+
+    if x > progress.furthest_x {
+        progress.furthest_x = x;
+    }
+}
+
+fn carry_player(
+    mut player_death_requester: EventWriter<RequestPlayerDeath>,
+    time: Res<Time>,
+    map: Res<Map>,
+    mut players: Query<(&mut Player, &mut KinematicCharacterController, &Transform)>,
+    carriers: Query<&Carrier>,
+) {
+    let Some((mut player, mut controller, transform)) = players.iter_mut().next() else {
+        return;
+    };
+    let Some(carrier) = player.carrier else {
+        return;
+    };
+    let Ok(carrier) = carriers.get(carrier) else {
+        player.carrier = None;
+        return;
+    };
+
+    let carrier_displacement = Vec3::Z * carrier.velocity * time.delta_seconds();
+
+    controller.translation =
+        Some(controller.translation.unwrap_or_default() + carrier_displacement);
+
+    let next_position_z = transform.translation.z + carrier_displacement.z;
+    if next_position_z < map.config.gameplay_min_z as f32 - 0.5
+        || next_position_z > map.config.gameplay_max_z as f32 + 0.5
+    {
+        player_death_requester.send(RequestPlayerDeath::new(PlayerDeathCause::OutOfBounds));
+    }
+}
+
 fn handle_move_keys(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    map: Res<Map>,
+    progress: Res<RunProgress>,
     mut players: Query<(&mut Player, &Transform)>,
 ) {
     let Some((mut player, player_transform)) = players.iter_mut().next() else {
@@ -222,6 +400,23 @@ fn handle_move_keys(
     if keyboard_input.any_just_released(PLAYER_MOVE_FORWARD_KEY_CODES) {
         player.jump_queue.push_back(PlayerJumpDirection::Forward);
     } else if keyboard_input.any_just_released(PLAYER_MOVE_BACK_KEY_CODES) {
+        let forward_queue = player
+            .jump_queue
+            .iter()
+            .filter(|jump| *jump == &PlayerJumpDirection::Forward)
+            .count() as i32;
+        let back_queue = player
+            .jump_queue
+            .iter()
+            .filter(|jump| *jump == &PlayerJumpDirection::Back)
+            .count() as i32;
+
+        if player_translation.x.round() as i32 + forward_queue - back_queue
+            <= progress.min_reachable_x()
+        {
+            return;
+        }
+
         player.jump_queue.push_back(PlayerJumpDirection::Back);
     } else if keyboard_input.any_just_released(PLAYER_MOVE_LEFT_KEY_CODES) {
         let left_queue = player
@@ -235,7 +430,9 @@ fn handle_move_keys(
             .filter(|jump| *jump == &PlayerJumpDirection::Right)
             .count() as i32;
 
-        if player_translation.z.round() as i32 - left_queue + right_queue <= MAP_GAMEPLAY_MIN_Z {
+        if player_translation.z.round() as i32 - left_queue + right_queue
+            <= map.config.gameplay_min_z
+        {
             return;
         }
 
@@ -252,7 +449,9 @@ fn handle_move_keys(
             .filter(|jump| *jump == &PlayerJumpDirection::Right)
             .count() as i32;
 
-        if player_translation.z.round() as i32 - left_queue + right_queue >= MAP_GAMEPLAY_MAX_Z {
+        if player_translation.z.round() as i32 - left_queue + right_queue
+            >= map.config.gameplay_max_z
+        {
             return;
         }
 
@@ -263,8 +462,11 @@ fn handle_move_keys(
 fn init_player_move(
     time: Res<Time>,
     map: Res<Map>,
+    progress: Res<RunProgress>,
+    physics: Res<PhysicsProfile>,
     mut players: Query<(&mut Player, &Transform, &Children)>,
     mut player_children: Query<(&Transform, &mut PlayerModel)>,
+    carriers: Query<(&Carrier, &Transform)>,
 ) {
     let Some((mut player, transform, children)) = players.iter_mut().next() else {
         return;
@@ -280,8 +482,12 @@ fn init_player_move(
     let Ok((child_transform, mut player_model)) = player_children.get_mut(*child) else {
         return;
     };
-    let Some(jump_direction) = player.jump_queue.front() else {
-        return;
+    let (jump_direction, is_sliding) = match player.slide_direction.take() {
+        Some(slide_direction) => (slide_direction, true),
+        None => match player.jump_queue.front() {
+            Some(jump_direction) => (*jump_direction, false),
+            None => return,
+        },
     };
 
     let player_translation = transform.translation;
@@ -290,36 +496,65 @@ fn init_player_move(
         PlayerJumpDirection::Back => (player_translation.x - 1.).round() - player_translation.x,
         _ => 0.,
     };
-    let target_z = match jump_direction {
-        PlayerJumpDirection::Left => (player_translation.z - 1.).round() - player_translation.z,
-        PlayerJumpDirection::Right => (player_translation.z + 1.).round() - player_translation.z,
-        _ => 0.,
+    // The player may stand between cells after being carried by a platform,
+    // so side hops stay on the spots of the carrier and other jumps return to the grid
+    let carrier = player
+        .carrier
+        .and_then(|carrier| carriers.get(carrier).ok());
+    let target_z = match (jump_direction, carrier) {
+        (PlayerJumpDirection::Left, Some((carrier, carrier_transform))) => {
+            carrier.spot_z(carrier_transform.translation.z, player_translation.z, -1.)
+                - player_translation.z
+        }
+        (PlayerJumpDirection::Right, Some((carrier, carrier_transform))) => {
+            carrier.spot_z(carrier_transform.translation.z, player_translation.z, 1.)
+                - player_translation.z
+        }
+        (PlayerJumpDirection::Left, None) => {
+            (player_translation.z - 1.).round() - player_translation.z
+        }
+        (PlayerJumpDirection::Right, None) => {
+            (player_translation.z + 1.).round() - player_translation.z
+        }
+        _ => player_translation.z.round() - player_translation.z,
     };
 
     let displacement_y = 0.;
     let displacement_xz = Vec3::new(target_x, 0., target_z);
 
     let final_position = (player_translation + displacement_xz).round();
-    if map
-        .obstacles_xz
-        .get(&IVec2::new(
-            final_position.x as i32,
-            final_position.z as i32,
-        ))
-        .is_some()
+    if !map
+        .tiles
+        .is_walkable(final_position.x as i32, final_position.z as i32)
     {
         return;
     }
 
-    let velocity_y = Vec3::Y * f32::sqrt(-2. * -GLOBAL_GRAVITY * PLAYER_JUMP_HEIGHT);
+    // Slides may continue a back jump that was right at the limit
+    if (final_position.x as i32) < progress.min_reachable_x() {
+        return;
+    }
+
+    // Unlike jumps from the queue, slides are never checked against the gameplay strip
+    if is_sliding && !map.config.is_gameplay_z(final_position.z as i32) {
+        return;
+    }
+
+    // Sliding is a barely noticeable hop, so the player stays on the ground the whole time
+    let jump_height = match is_sliding {
+        true => physics.slide_height,
+        false => physics.jump_height,
+    };
+    let gravity = physics.gravity;
+    let velocity_y = Vec3::Y * f32::sqrt(-2. * -gravity * jump_height);
     let velocity_xz = displacement_xz
-        / (f32::sqrt(-2. * PLAYER_JUMP_HEIGHT / -GLOBAL_GRAVITY)
-            + f32::sqrt(2. * (displacement_y - PLAYER_JUMP_HEIGHT) / -GLOBAL_GRAVITY));
+        / (f32::sqrt(-2. * jump_height / -gravity)
+            + f32::sqrt(2. * (displacement_y - jump_height) / -gravity));
 
     let child_rotation_y = child_transform.rotation.to_scaled_axis().y;
 
     player_model.rotation_start_at = Some(time.elapsed_seconds());
-    player_model.rotation_duration = Some(velocity_y.y / GLOBAL_GRAVITY * 2.);
+    player_model.rotation_duration = Some(velocity_y.y / gravity * 2.);
     player_model.start_rotation = Some(child_rotation_y);
     #[rustfmt::skip]
     let end_rotation = match jump_direction {
@@ -377,8 +612,19 @@ fn init_player_move(
     player_model.end_rotation = end_rotation;
 
     player.velocity = velocity_xz + velocity_y;
+    player.gravity = gravity;
+    player.last_jump_direction = jump_direction;
+    player.is_sliding = is_sliding;
     player.is_grounded = false;
     player.is_initial_jump_made = false;
+
+    // Side hops keep moving with the carrier, so the player lands on its next spot
+    if !matches!(
+        jump_direction,
+        PlayerJumpDirection::Left | PlayerJumpDirection::Right
+    ) {
+        player.carrier = None;
+    }
 }
 
 fn flatten_player(
@@ -446,3 +692,68 @@ fn flatten_player(
 
     child_animator.set_tweenable(sequence);
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::event::Events;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::ecs::world::World;
+    use bevy::transform::components::Transform;
+
+    use super::{handle_player_landing, Player};
+    use crate::events::{RequestCoinPickup, RequestPlayerDeath};
+    use crate::world::tiles::{TileGrid, TileKind};
+    use crate::world::Map;
+
+    fn world_with_river() -> World {
+        let mut world = World::new();
+        world.init_resource::<Events<RequestPlayerDeath>>();
+        world.init_resource::<Events<RequestCoinPickup>>();
+
+        let mut map = Map {
+            tiles: TileGrid::new(-2..3),
+            ..Default::default()
+        };
+        map.tiles.insert_row(0, |_| TileKind::Water);
+        world.insert_resource(map);
+
+        world.spawn((
+            Player {
+                is_just_landed: true,
+                ..Default::default()
+            },
+            Transform::from_xyz(0., 1., 0.),
+        ));
+
+        world
+    }
+
+    #[test]
+    fn player_landing_on_a_platform_rides_it() {
+        let mut world = world_with_river();
+        let carrier = world.spawn_empty().id();
+        world
+            .resource_mut::<Map>()
+            .tiles
+            .get_mut(0, 0)
+            .unwrap()
+            .platform = Some(carrier);
+
+        world.run_system_once(handle_player_landing);
+
+        let player = world.query::<&Player>().single(&world);
+        assert_eq!(player.carrier, Some(carrier));
+        assert!(world.resource::<Events<RequestPlayerDeath>>().is_empty());
+    }
+
+    #[test]
+    fn player_landing_in_water_drowns() {
+        let mut world = world_with_river();
+
+        world.run_system_once(handle_player_landing);
+
+        let player = world.query::<&Player>().single(&world);
+        assert_eq!(player.carrier, None);
+        assert_eq!(world.resource::<Events<RequestPlayerDeath>>().len(), 1);
+    }
+}
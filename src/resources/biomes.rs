@@ -0,0 +1,326 @@
+use std::error::Error;
+use std::fs;
+use std::hash::Hash;
+
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::Reader;
+use bevy::asset::{
+    ron, Asset, AssetLoader, AssetServer, Assets, AsyncReadExt, BoxedFuture, Handle, LoadContext,
+};
+use bevy::prelude::{Color, Commands, Res, ResMut, Resource};
+use bevy::reflect::TypePath;
+use bevy::utils::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{GLOBAL_GRAVITY, PLAYER_JUMP_HEIGHT, PLAYER_SLIDE_HEIGHT};
+use crate::resources::AssetLoading;
+use crate::states::CurrentBiome;
+
+/// Colour in the sRGB space with 0-255 components.
+pub type ColorDefinition = (u8, u8, u8);
+
+/// Everything that makes a biome look and generate differently,
+/// loaded from `assets/biomes/{name}.biome.ron`.
+#[derive(Asset, TypePath, Debug, Deserialize)]
+pub struct BiomeDefinition {
+    pub lighting: Vec<LightDefinition>,
+    pub ground_palette: GroundPalette,
+    #[serde(default)]
+    pub physics: PhysicsProfile,
+    /// Regions by their names. It's up to the biome which names it knows.
+    #[serde(default)]
+    pub regions: HashMap<String, RegionDefinition>,
+}
+
+impl BiomeDefinition {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let definition: Self = ron::de::from_bytes(bytes)?;
+        definition.physics.validate()?;
+
+        Ok(definition)
+    }
+
+    /// Reads the definition from the assets folder right away, without the asset server.
+    fn read(path: &str) -> Self {
+        let path = FileAssetReader::get_base_path().join("assets").join(path);
+
+        fs::read(&path)
+            .map_err(Box::from)
+            .and_then(|bytes| Self::from_bytes(&bytes))
+            .unwrap_or_else(|error| panic!("Failed to load {}: {error}", path.display()))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LightDefinition {
+    pub color: ColorDefinition,
+    pub illuminance: f32,
+    /// The light always looks at the origin.
+    pub translation: (f32, f32, f32),
+    #[serde(default)]
+    pub shadows_enabled: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct GroundPalette {
+    pub light_grass: ColorDefinition,
+    pub dark_grass: ColorDefinition,
+    pub asphalt: ColorDefinition,
+    pub water: ColorDefinition,
+    pub gravel: ColorDefinition,
+    pub ice: ColorDefinition,
+}
+
+/// How the player moves in a biome. The resource always holds the profile
+/// of the biome the player is currently in.
+#[derive(Clone, Debug, Deserialize, PartialEq, Resource)]
+pub struct PhysicsProfile {
+    pub gravity: f32,
+    pub jump_height: f32,
+    pub slide_height: f32,
+}
+
+impl PhysicsProfile {
+    /// Jumps take square roots of the heights and divide by the gravity,
+    /// so anything but positive values would send the player flying.
+    fn validate(&self) -> Result<(), String> {
+        match self.gravity > 0. && self.jump_height > 0. && self.slide_height > 0. {
+            true => Ok(()),
+            false => Err(format!("Physics values must be positive: {self:?}")),
+        }
+    }
+}
+
+impl Default for PhysicsProfile {
+    fn default() -> Self {
+        Self {
+            gravity: GLOBAL_GRAVITY,
+            jump_height: PLAYER_JUMP_HEIGHT,
+            slide_height: PLAYER_SLIDE_HEIGHT,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RegionDefinition {
+    /// Minimum and maximum number of chunks in a single run of the region.
+    pub run_length: (u32, u32),
+    /// Names of regions that may follow the run, with their weights.
+    pub transitions: Vec<(String, u32)>,
+    /// What is spawned on every cell of the region, with weights.
+    #[serde(default)]
+    pub obstacles: Vec<(ObstacleKind, u32)>,
+    /// Chance in percent that a chunk of the region has a coin on one of its free cells.
+    #[serde(default)]
+    pub coin_chance: u32,
+    /// What decorates every cell outside the gameplay strip, with weights.
+    #[serde(default)]
+    pub scenery: Vec<(SceneryKind, u32)>,
+    /// What lies on every free cell of the gameplay strip, with weights.
+    #[serde(default)]
+    pub decorations: Vec<(DecorationKind, u32)>,
+}
+
+/// Tables of all regions of a biome, keyed by the region type of the biome.
+/// A region without an entry has no coins, scenery, decorations or obstacles.
+#[derive(Clone, Debug)]
+pub struct RegionTables<R> {
+    /// Chance in percent that a chunk of the region has a coin.
+    pub coin_chances: HashMap<R, u32>,
+    /// Weight tables of what decorates the cells outside the gameplay strip.
+    pub scenery: HashMap<R, Vec<(SceneryKind, u32)>>,
+    /// Weight tables of what lies on the free cells of the gameplay strip.
+    pub decorations: HashMap<R, Vec<(DecorationKind, u32)>>,
+    /// Weight tables of what stands on the cells of the gameplay strip.
+    pub obstacles: HashMap<R, Vec<(ObstacleKind, u32)>>,
+}
+
+impl<R> Default for RegionTables<R> {
+    fn default() -> Self {
+        Self {
+            coin_chances: HashMap::default(),
+            scenery: HashMap::default(),
+            decorations: HashMap::default(),
+            obstacles: HashMap::default(),
+        }
+    }
+}
+
+impl<R: Copy + Eq + Hash> RegionTables<R> {
+    /// Reads the tables of the regions that `parse_region` knows from the biome definition.
+    pub fn parse(definition: &BiomeDefinition, parse_region: impl Fn(&str) -> Option<R>) -> Self {
+        let mut tables = Self::default();
+
+        for (name, region) in &definition.regions {
+            let Some(key) = parse_region(name) else {
+                continue;
+            };
+
+            if region.coin_chance > 0 {
+                tables.coin_chances.insert(key, region.coin_chance);
+            }
+            if !region.scenery.is_empty() {
+                tables.scenery.insert(key, region.scenery.clone());
+            }
+            if !region.decorations.is_empty() {
+                tables.decorations.insert(key, region.decorations.clone());
+            }
+            if !region.obstacles.is_empty() {
+                tables.obstacles.insert(key, region.obstacles.clone());
+            }
+        }
+
+        tables
+    }
+
+    /// Empty if the region doesn't spawn obstacles.
+    pub fn obstacles_of(&self, region: R) -> &[(ObstacleKind, u32)] {
+        self.obstacles
+            .get(&region)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ObstacleKind {
+    Empty,
+    Tree,
+    Boulder,
+    Stump,
+}
+
+/// Decorations of the dimmed cells outside the gameplay strip. They never block the player.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum SceneryKind {
+    Empty,
+    Tree,
+    Boulder,
+    Stump,
+    Fence,
+    Snowman,
+    Crystal,
+}
+
+/// Small things on the walkable cells of the gameplay strip. The player walks right over them.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum DecorationKind {
+    Empty,
+    Flower,
+    Tuft,
+    Pebble,
+    Puddle,
+}
+
+pub fn to_color((red, green, blue): ColorDefinition) -> Color {
+    Color::rgb_u8(red, green, blue)
+}
+
+#[derive(Default)]
+pub(super) struct BiomeDefinitionLoader;
+
+impl AssetLoader for BiomeDefinitionLoader {
+    type Asset = BiomeDefinition;
+    type Settings = ();
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _: &'a Self::Settings,
+        _: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+
+            BiomeDefinition::from_bytes(&bytes)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["biome.ron"]
+    }
+}
+
+#[derive(Debug, Resource)]
+pub struct BiomeCollection {
+    #[cfg(feature = "debug")]
+    pub default: Handle<BiomeDefinition>,
+    pub crossy_valley: Handle<BiomeDefinition>,
+    pub winter: Handle<BiomeDefinition>,
+    pub moon: Handle<BiomeDefinition>,
+}
+
+impl BiomeCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut definitions: ResMut<Assets<BiomeDefinition>>,
+        mut asset_loading: ResMut<AssetLoading>,
+        asset_server: Res<AssetServer>,
+    ) {
+        let mut load = |name: &str| {
+            let path = format!("biomes/{name}.biome.ron");
+
+            if asset_loading.is_headless {
+                return definitions.add(BiomeDefinition::read(&path));
+            }
+
+            let handle = asset_server.load(path);
+            asset_loading.handles.push(handle.clone_weak().untyped());
+
+            handle
+        };
+
+        let collection = Self {
+            #[cfg(feature = "debug")]
+            default: load("default"),
+            crossy_valley: load("crossy_valley"),
+            winter: load("winter"),
+            moon: load("moon"),
+        };
+
+        commands.insert_resource(collection);
+    }
+
+    /// Fails right after loading if any definition is missing, rather than once the biome is entered.
+    pub(super) fn check_definitions(
+        biomes: Res<Self>,
+        definitions: Res<Assets<BiomeDefinition>>,
+        asset_server: Res<AssetServer>,
+    ) {
+        let handles = [
+            #[cfg(feature = "debug")]
+            &biomes.default,
+            &biomes.crossy_valley,
+            &biomes.winter,
+            &biomes.moon,
+        ];
+
+        for handle in handles {
+            if !definitions.contains(handle) {
+                let path = asset_server.get_path(handle).map(|path| path.to_string());
+                panic!("Missing the biome definition {}", path.unwrap_or_default());
+            }
+        }
+    }
+
+    /// Definitions are checked before the world is initialised,
+    /// so this only returns `None` for [CurrentBiome::None].
+    pub fn get<'a>(
+        &self,
+        biome: CurrentBiome,
+        definitions: &'a Assets<BiomeDefinition>,
+    ) -> Option<&'a BiomeDefinition> {
+        let handle = match biome {
+            #[cfg(feature = "debug")]
+            CurrentBiome::Default => &self.default,
+            CurrentBiome::CrossyValley => &self.crossy_valley,
+            CurrentBiome::Winter => &self.winter,
+            CurrentBiome::Moon => &self.moon,
+            CurrentBiome::None => return None,
+        };
+
+        definitions.get(handle)
+    }
+}
@@ -1,12 +1,15 @@
 use bevy::asset::{AssetServer, Assets};
+use bevy::math::Vec3;
 use bevy::pbr::StandardMaterial;
-use bevy::prelude::{Commands, Mesh, Res, ResMut, Resource};
+use bevy::prelude::{Color, Commands, Cuboid, Mesh, Res, ResMut, Resource};
 
 use crate::resources::{AssetLoading, Model};
 use crate::states::CurrentBiome;
 
 #[derive(Clone, Debug)]
 pub struct Character {
+    pub name: &'static str,
+    /// Every run of the character starts in this biome.
     pub biome: CurrentBiome,
     pub model: Model,
 }
@@ -14,17 +17,25 @@ pub struct Character {
 #[derive(Clone, Debug, Resource)]
 pub struct CharacterCollection {
     pub chicken: Character,
+    pub penguin: Character,
 }
 
 impl CharacterCollection {
     pub(super) fn setup(
         mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
         mut materials: ResMut<Assets<StandardMaterial>>,
         mut asset_loading: ResMut<AssetLoading>,
         asset_server: Res<AssetServer>,
     ) {
+        // Placeholder until the penguin gets a proper model
+        let penguin_size = Vec3::new(0.5, 0.7, 0.5);
+        let penguin = meshes.add(Cuboid::from_size(penguin_size));
+        let dark_blue = materials.add(Color::rgb(40. / 255., 46. / 255., 66. / 255.));
+
         let collection = Self {
             chicken: Character {
+                name: "chicken",
                 biome: CurrentBiome::CrossyValley,
                 model: Model::load(
                     &mut materials,
@@ -34,6 +45,11 @@ impl CharacterCollection {
                     "characters/chicken",
                 ),
             },
+            penguin: Character {
+                name: "penguin",
+                biome: CurrentBiome::Winter,
+                model: Model::new(penguin, penguin_size, dark_blue),
+            },
         };
 
         commands.insert_resource(collection);
@@ -42,4 +58,10 @@ impl CharacterCollection {
     pub fn calculate_mesh_sizes(mut characters: ResMut<Self>, meshes: Res<Assets<Mesh>>) {
         characters.chicken.model.calculate_mesh_size(&meshes);
     }
+
+    pub fn get(&self, name: &str) -> Option<&Character> {
+        [&self.chicken, &self.penguin]
+            .into_iter()
+            .find(|character| character.name == name)
+    }
 }
@@ -0,0 +1,39 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::asset::Assets;
+use bevy::math::{Quat, Vec3};
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Color, Commands, Cylinder, Mesh, ResMut, Resource};
+
+use crate::resources::Model;
+
+#[derive(Debug, Resource)]
+pub struct CoinCollection {
+    pub coin: Model,
+}
+
+impl CoinCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        let gold = materials.add(StandardMaterial {
+            base_color: Color::rgb_u8(255, 200, 40),
+            metallic: 0.5,
+            perceptual_roughness: 0.4,
+            ..Default::default()
+        });
+
+        // The coin stands on its edge and spins around the y axis
+        let size = Vec3::new(0.4, 0.4, 0.08);
+        let mesh = Mesh::from(Cylinder::new(size.x / 2., size.z))
+            .rotated_by(Quat::from_rotation_x(FRAC_PI_2));
+
+        let collection = Self {
+            coin: Model::new(meshes.add(mesh), size, gold),
+        };
+
+        commands.insert_resource(collection);
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::asset::Assets;
+use bevy::math::Vec3;
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Color, Commands, Cuboid, Cylinder, Mesh, ResMut, Resource};
+
+use crate::resources::Model;
+
+/// Small props that lie on the walkable cells and never get in the player's way.
+#[derive(Debug, Resource)]
+pub struct DecorationCollection {
+    pub flower: Model,
+    pub tuft: Model,
+    pub pebble: Model,
+    pub puddle: Model,
+}
+
+impl DecorationCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        let flower_size = Vec3::new(0.12, 0.2, 0.12);
+        let tuft_size = Vec3::new(0.25, 0.12, 0.25);
+        let pebble_size = Vec3::new(0.15, 0.08, 0.12);
+        // Puddles are flat discs, wide enough to stand out from the ground
+        let puddle_size = Vec3::new(0.6, 0.02, 0.6);
+
+        let collection = Self {
+            flower: Model::new(
+                meshes.add(Cuboid::from_size(flower_size)),
+                flower_size,
+                materials.add(Color::rgb_u8(240, 120, 170)),
+            ),
+            tuft: Model::new(
+                meshes.add(Cuboid::from_size(tuft_size)),
+                tuft_size,
+                materials.add(Color::rgb_u8(120, 180, 70)),
+            ),
+            pebble: Model::new(
+                meshes.add(Cuboid::from_size(pebble_size)),
+                pebble_size,
+                materials.add(Color::rgb_u8(150, 150, 155)),
+            ),
+            puddle: Model::new(
+                meshes.add(Cylinder::new(puddle_size.x / 2., puddle_size.y)),
+                puddle_size,
+                materials.add(StandardMaterial {
+                    base_color: Color::rgb_u8(110, 170, 220),
+                    perceptual_roughness: 0.1,
+                    ..Default::default()
+                }),
+            ),
+        };
+
+        commands.insert_resource(collection);
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::asset::Assets;
+use bevy::math::Vec3;
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Color, Commands, Cuboid, Mesh, ResMut, Resource};
+
+use crate::resources::Model;
+
+#[derive(Debug, Resource)]
+pub struct EagleCollection {
+    pub eagle: Model,
+}
+
+impl EagleCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        // Wings are spread along the z axis
+        let size = Vec3::new(0.9, 0.3, 2.2);
+        let brown = materials.add(Color::rgb(110. / 255., 72. / 255., 46. / 255.));
+
+        let collection = Self {
+            eagle: Model::new(meshes.add(Cuboid::from_size(size)), size, brown),
+        };
+
+        commands.insert_resource(collection);
+    }
+}
@@ -1,58 +1,143 @@
-use bevy::asset::Assets;
+use bevy::asset::{Assets, Handle};
 use bevy::math::Vec3;
 use bevy::pbr::StandardMaterial;
 use bevy::prelude::{Color, Commands, Cuboid, Mesh, ResMut, Resource};
+use bevy::render::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::utils::HashMap;
 
-use crate::resources::Model;
+use crate::resources::biomes::{to_color, ColorDefinition, GroundPalette};
+use crate::states::CurrentBiome;
+use crate::world::tiles::TileKind;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Ground {
-    pub default: Model,
-    pub dimmed: Model,
+    pub kind: TileKind,
+    pub default: Color,
+    /// Used outside the gameplay strip. All rows share a single material,
+    /// so the cells are darkened instead of getting a metallic material of their own.
+    pub dimmed: Color,
 }
 
-#[derive(Debug, Resource)]
-pub struct GroundCollection {
+impl Ground {
+    fn new(kind: TileKind, color: ColorDefinition) -> Self {
+        let color = to_color(color);
+
+        Self {
+            kind,
+            default: color,
+            dimmed: color * 0.75,
+        }
+    }
+}
+
+/// Ground colours from the palette of a single biome.
+#[derive(Clone, Copy, Debug)]
+pub struct Grounds {
     pub light_cube: Ground,
     pub dark_cube: Ground,
+    pub asphalt_cube: Ground,
+    pub water_cube: Ground,
+    pub gravel_cube: Ground,
+    pub ice_cube: Ground,
 }
 
-impl GroundCollection {
-    pub(super) fn setup(
-        mut commands: Commands,
-        mut meshes: ResMut<Assets<Mesh>>,
-        mut materials: ResMut<Assets<StandardMaterial>>,
-    ) {
-        let cube = meshes.add(Cuboid::from_size(Vec3::splat(1.)));
-
-        let light_green: StandardMaterial =
-            Color::rgb(174. / 255., 224. / 255., 102. / 255.).into();
-        let dark_green: StandardMaterial = Color::rgb(167. / 255., 217. / 255., 94. / 255.).into();
-        let light_dimmed_green = StandardMaterial {
-            metallic: 0.5,
-            ..light_green.clone()
-        };
-        let dark_dimmed_green = StandardMaterial {
-            metallic: 0.5,
-            ..dark_green.clone()
-        };
-
-        let light_green = materials.add(light_green);
-        let light_dimmed_green = materials.add(light_dimmed_green);
-        let dark_green = materials.add(dark_green);
-        let dark_dimmed_green = materials.add(dark_dimmed_green);
+/// Colours are stored in the vertices of the ground meshes,
+/// so chunks of the previous biome keep them while the next biome is spawning.
+#[derive(Debug, Resource)]
+pub struct GroundCollection {
+    /// Every ground row is merged from copies of this cube.
+    cube: Mesh,
+    /// Shared by all ground rows, it only multiplies the vertex colours.
+    pub material: Handle<StandardMaterial>,
+    palettes: HashMap<CurrentBiome, Grounds>,
+    /// Merged row meshes by the bits of their colours.
+    /// Rows only come in a few colour patterns, so the meshes are built once and shared.
+    row_meshes: HashMap<Vec<[u32; 4]>, Handle<Mesh>>,
+}
 
+impl GroundCollection {
+    pub(super) fn setup(mut commands: Commands, mut materials: ResMut<Assets<StandardMaterial>>) {
         let collection = Self {
-            light_cube: Ground {
-                default: Model::new(cube.clone(), Vec3::ONE, light_green),
-                dimmed: Model::new(cube.clone(), Vec3::ONE, light_dimmed_green),
-            },
-            dark_cube: Ground {
-                default: Model::new(cube.clone(), Vec3::ONE, dark_green),
-                dimmed: Model::new(cube, Vec3::ONE, dark_dimmed_green),
-            },
+            cube: Cuboid::from_size(Vec3::splat(1.)).into(),
+            material: materials.add(Color::WHITE),
+            palettes: HashMap::new(),
+            row_meshes: HashMap::new(),
         };
 
         commands.insert_resource(collection);
     }
+
+    /// Reads the palette of the biome once, it's reused on the next runs.
+    pub fn insert_palette(&mut self, biome: CurrentBiome, palette: &GroundPalette) {
+        self.palettes.entry(biome).or_insert_with(|| Grounds {
+            light_cube: Ground::new(TileKind::Ground, palette.light_grass),
+            dark_cube: Ground::new(TileKind::Ground, palette.dark_grass),
+            asphalt_cube: Ground::new(TileKind::Road, palette.asphalt),
+            water_cube: Ground::new(TileKind::Water, palette.water),
+            gravel_cube: Ground::new(TileKind::Rail, palette.gravel),
+            ice_cube: Ground::new(TileKind::Ice, palette.ice),
+        });
+    }
+
+    /// Panics if the biome has never been entered.
+    pub fn get(&self, biome: CurrentBiome) -> &Grounds {
+        self.palettes
+            .get(&biome)
+            .expect("Failed to get grounds of the biome")
+    }
+
+    /// Mesh of a ground row with a cube for every colour, built on the first request.
+    pub fn row_mesh(&mut self, meshes: &mut Assets<Mesh>, colors: &[Color]) -> Handle<Mesh> {
+        let key = colors
+            .iter()
+            .map(|color| color.as_linear_rgba_f32().map(f32::to_bits))
+            .collect();
+
+        self.row_meshes
+            .entry(key)
+            .or_insert_with(|| meshes.add(merge_cubes(&self.cube, colors)))
+            .clone()
+    }
+}
+
+/// Merges a copy of the cube for every colour into a single mesh.
+/// Cubes are placed one after another along the z axis, centred on the origin.
+fn merge_cubes(cube: &Mesh, colors: &[Color]) -> Mesh {
+    let positions = cube
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(VertexAttributeValues::as_float3)
+        .expect("Failed to get positions of the ground cube");
+    let normals = cube
+        .attribute(Mesh::ATTRIBUTE_NORMAL)
+        .and_then(VertexAttributeValues::as_float3)
+        .expect("Failed to get normals of the ground cube");
+    let indices = cube
+        .indices()
+        .expect("Failed to get indices of the ground cube");
+
+    let first_z = -(colors.len() as f32 - 1.) / 2.;
+    let mut row_positions = Vec::with_capacity(positions.len() * colors.len());
+    let mut row_normals = Vec::with_capacity(positions.len() * colors.len());
+    let mut row_colors = Vec::with_capacity(positions.len() * colors.len());
+    let mut row_indices = Vec::with_capacity(indices.len() * colors.len());
+
+    for (index, color) in colors.iter().enumerate() {
+        let z = first_z + index as f32;
+        let first_vertex = row_positions.len() as u32;
+
+        row_positions.extend(positions.iter().map(|[x, y, cube_z]| [*x, *y, cube_z + z]));
+        row_normals.extend_from_slice(normals);
+        row_colors.resize(row_positions.len(), color.as_linear_rgba_f32());
+        row_indices.extend(indices.iter().map(|index| first_vertex + index as u32));
+    }
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, row_positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, row_normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, row_colors)
+    .with_inserted_indices(Indices::U32(row_indices))
 }
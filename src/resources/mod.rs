@@ -1,5 +1,5 @@
 use bevy::app::{App, Plugin, Update};
-use bevy::asset::{AssetServer, Assets, Handle, LoadState, UntypedHandle};
+use bevy::asset::{AssetApp, AssetServer, Assets, Handle, LoadState, UntypedHandle};
 use bevy::math::Vec3;
 use bevy::pbr::StandardMaterial;
 use bevy::prelude::{
@@ -7,15 +7,31 @@ use bevy::prelude::{
     States,
 };
 
+use crate::resources::biomes::{BiomeCollection, BiomeDefinition, BiomeDefinitionLoader};
 use crate::resources::characters::CharacterCollection;
+use crate::resources::coins::CoinCollection;
+use crate::resources::decorations::DecorationCollection;
+use crate::resources::eagles::EagleCollection;
 use crate::resources::grounds::GroundCollection;
 use crate::resources::obstacles::ObstacleCollection;
+use crate::resources::platforms::PlatformCollection;
+use crate::resources::railways::RailwayCollection;
+use crate::resources::scenery::SceneryCollection;
+use crate::resources::vehicles::VehicleCollection;
 use crate::states::AppState;
 use crate::utils;
 
+pub mod biomes;
 pub mod characters;
+pub mod coins;
+pub mod decorations;
+pub mod eagles;
 pub mod grounds;
 pub mod obstacles;
+pub mod platforms;
+pub mod railways;
+pub mod scenery;
+pub mod vehicles;
 
 #[derive(Clone, Debug, Default, Hash, PartialEq, Eq, States)]
 enum InitModelsState {
@@ -25,24 +41,44 @@ enum InitModelsState {
     Finished,
 }
 
-pub struct ResourcePlugin;
+#[derive(Default)]
+pub struct ResourcePlugin {
+    /// Apps without a renderer, like the map generator, never draw the models.
+    /// They get placeholders instead, and the biome definitions are read right away,
+    /// so nothing is loaded in the background.
+    pub is_headless: bool,
+}
 
 impl Plugin for ResourcePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<InitModelsState>()
-            .init_resource::<AssetLoading>()
+            .init_asset::<BiomeDefinition>()
+            .init_asset_loader::<BiomeDefinitionLoader>()
+            .insert_resource(AssetLoading {
+                handles: Vec::new(),
+                is_headless: self.is_headless,
+            })
             .add_systems(
                 Update,
                 (
                     (
+                        BiomeCollection::setup,
                         CharacterCollection::setup,
+                        CoinCollection::setup,
+                        DecorationCollection::setup,
+                        EagleCollection::setup,
                         GroundCollection::setup,
                         ObstacleCollection::setup,
+                        PlatformCollection::setup,
+                        RailwayCollection::setup,
+                        SceneryCollection::setup,
+                        VehicleCollection::setup,
                     )
                         .distributive_run_if(run_once()),
                     check_assets_ready.run_if(in_state(InitModelsState::Loading)),
                     (
                         (
+                            BiomeCollection::check_definitions,
                             CharacterCollection::calculate_mesh_sizes,
                             ObstacleCollection::calculate_mesh_sizes,
                         ),
@@ -57,9 +93,10 @@ impl Plugin for ResourcePlugin {
     }
 }
 
-#[derive(Default, Resource)]
+#[derive(Resource)]
 struct AssetLoading {
     handles: Vec<UntypedHandle>,
+    is_headless: bool,
 }
 
 fn check_assets_ready(
@@ -71,7 +108,10 @@ fn check_assets_ready(
     for handle in &loading.handles {
         match server.load_state(handle.id()) {
             LoadState::Loaded => {}
-            LoadState::Failed => panic!("Failed to load some assets"),
+            LoadState::Failed => {
+                let path = server.get_path(handle.id()).map(|path| path.to_string());
+                panic!("Failed to load {}", path.unwrap_or_default());
+            }
             _ => return,
         }
     }
@@ -112,6 +152,10 @@ impl Model {
         model_path: &str,
         texture_path: &str,
     ) -> Self {
+        if asset_loading.is_headless {
+            return Self::placeholder(materials.add(StandardMaterial::default()));
+        }
+
         let mesh = asset_server.load(format!("models/{model_path}.glb#Mesh0/Primitive0"));
         asset_loading.handles.push(mesh.clone_weak().untyped());
 
@@ -137,6 +181,10 @@ impl Model {
         asset_server: &Res<AssetServer>,
         path: &str,
     ) -> Self {
+        if asset_loading.is_headless {
+            return Self::placeholder(material);
+        }
+
         let mesh = asset_server.load(format!("models/{path}.glb#Mesh0/Primitive0"));
         asset_loading.handles.push(mesh.clone_weak().untyped());
 
@@ -147,7 +195,17 @@ impl Model {
         }
     }
 
+    /// Unit sized model without a mesh, used instead of the loaded ones in headless apps.
+    fn placeholder(material: Handle<StandardMaterial>) -> Self {
+        Self::new(Handle::default(), Vec3::ONE, material)
+    }
+
+    /// Only loaded models need it, placeholders already have a size.
     pub fn calculate_mesh_size(&mut self, meshes: &Res<Assets<Mesh>>) {
+        if !self.mesh_size.is_nan() {
+            return;
+        }
+
         let mesh = meshes
             .get(self.mesh.id())
             .expect("Failed to get Mesh from Model");
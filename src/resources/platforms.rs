@@ -0,0 +1,55 @@
+use bevy::asset::Assets;
+use bevy::math::Vec3;
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Color, Commands, Cuboid, Mesh, ResMut, Resource};
+use oorandom::Rand32;
+
+use crate::resources::Model;
+
+#[derive(Debug, Resource)]
+pub struct PlatformCollection {
+    pub logs: LogPlatforms,
+}
+
+impl PlatformCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        let wood = materials.add(Color::rgb(143. / 255., 96. / 255., 66. / 255.));
+
+        let log = |meshes: &mut ResMut<Assets<Mesh>>, length: f32| {
+            let size = Vec3::new(0.8, 0.25, length);
+
+            Model::new(meshes.add(Cuboid::from_size(size)), size, wood.clone())
+        };
+
+        let collection = Self {
+            logs: LogPlatforms {
+                short: log(&mut meshes, 2.),
+                medium: log(&mut meshes, 3.),
+                long: log(&mut meshes, 4.),
+            },
+        };
+
+        commands.insert_resource(collection);
+    }
+}
+
+#[derive(Debug)]
+pub struct LogPlatforms {
+    short: Model,
+    medium: Model,
+    long: Model,
+}
+
+impl LogPlatforms {
+    pub fn get_random(&self, random_generator: &mut Rand32) -> &Model {
+        match random_generator.rand_range(0..3) {
+            0 => &self.short,
+            1 => &self.medium,
+            _ => &self.long,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::asset::Assets;
+use bevy::math::Vec3;
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Color, Commands, Cuboid, Mesh, ResMut, Resource};
+
+use crate::resources::Model;
+
+#[derive(Debug)]
+pub struct SignalLamp {
+    pub off: Model,
+    pub on: Model,
+}
+
+#[derive(Debug, Resource)]
+pub struct RailwayCollection {
+    pub signal_pole: Model,
+    pub signal_lamp: SignalLamp,
+}
+
+impl RailwayCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        let pole_size = Vec3::new(0.15, 1.2, 0.15);
+        let pole = meshes.add(Cuboid::from_size(pole_size));
+        let lamp_size = Vec3::new(0.2, 0.3, 0.3);
+        let lamp = meshes.add(Cuboid::from_size(lamp_size));
+
+        let grey = materials.add(Color::rgb(120. / 255., 120. / 255., 128. / 255.));
+        let dark_red = materials.add(Color::rgb(92. / 255., 28. / 255., 28. / 255.));
+        let red = materials.add(StandardMaterial {
+            emissive: Color::rgb(1., 0.2, 0.2),
+            ..Color::rgb(1., 0.2, 0.2).into()
+        });
+
+        let collection = Self {
+            signal_pole: Model::new(pole, pole_size, grey),
+            signal_lamp: SignalLamp {
+                off: Model::new(lamp.clone(), lamp_size, dark_red),
+                on: Model::new(lamp, lamp_size, red),
+            },
+        };
+
+        commands.insert_resource(collection);
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::asset::Assets;
+use bevy::math::Vec3;
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Capsule3d, Color, Commands, Cuboid, Mesh, ResMut, Resource};
+
+use crate::resources::Model;
+
+/// Props that only decorate the cells outside the gameplay strip.
+/// Trees, boulders and stumps are shared with the obstacles.
+#[derive(Debug, Resource)]
+pub struct SceneryCollection {
+    pub fence: Model,
+    pub snowman: Model,
+    pub crystal: Model,
+}
+
+impl SceneryCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        // Fences span the whole cell along the x axis, so neighbouring ones join into a line
+        let fence_size = Vec3::new(1., 0.5, 0.1);
+        let wood = materials.add(Color::rgb_u8(150, 108, 70));
+
+        let snowman_size = Vec3::new(0.6, 1., 0.6);
+        let snow = materials.add(Color::rgb_u8(245, 248, 252));
+
+        let crystal_size = Vec3::new(0.3, 0.9, 0.3);
+        let crystal = materials.add(StandardMaterial {
+            base_color: Color::rgb_u8(190, 160, 255),
+            emissive: Color::rgb_u8(60, 40, 110),
+            ..Default::default()
+        });
+
+        let collection = Self {
+            fence: Model::new(meshes.add(Cuboid::from_size(fence_size)), fence_size, wood),
+            snowman: Model::new(
+                meshes.add(Capsule3d::new(
+                    snowman_size.x / 2.,
+                    snowman_size.y - snowman_size.x,
+                )),
+                snowman_size,
+                snow,
+            ),
+            crystal: Model::new(
+                meshes.add(Cuboid::from_size(crystal_size)),
+                crystal_size,
+                crystal,
+            ),
+        };
+
+        commands.insert_resource(collection);
+    }
+}
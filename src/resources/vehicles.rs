@@ -0,0 +1,64 @@
+use bevy::asset::Assets;
+use bevy::math::Vec3;
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Color, Commands, Cuboid, Mesh, ResMut, Resource};
+use oorandom::Rand32;
+
+use crate::resources::Model;
+
+#[derive(Debug, Resource)]
+pub struct VehicleCollection {
+    pub cars: CarVehicles,
+    pub truck: Model,
+    pub train: Model,
+}
+
+impl VehicleCollection {
+    pub(super) fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        let car_size = Vec3::new(0.8, 0.6, 1.4);
+        let car = meshes.add(Cuboid::from_size(car_size));
+        let truck_size = Vec3::new(0.9, 0.8, 2.4);
+        let truck = meshes.add(Cuboid::from_size(truck_size));
+        let train_size = Vec3::new(0.9, 0.9, 9.);
+        let train = meshes.add(Cuboid::from_size(train_size));
+
+        let red = materials.add(Color::rgb(226. / 255., 73. / 255., 73. / 255.));
+        let blue = materials.add(Color::rgb(73. / 255., 133. / 255., 226. / 255.));
+        let yellow = materials.add(Color::rgb(240. / 255., 200. / 255., 66. / 255.));
+        let white = materials.add(Color::rgb(236. / 255., 236. / 255., 236. / 255.));
+        let dark_blue = materials.add(Color::rgb(52. / 255., 64. / 255., 92. / 255.));
+
+        let collection = Self {
+            cars: CarVehicles {
+                red: Model::new(car.clone(), car_size, red),
+                blue: Model::new(car.clone(), car_size, blue),
+                yellow: Model::new(car, car_size, yellow),
+            },
+            truck: Model::new(truck, truck_size, white),
+            train: Model::new(train, train_size, dark_blue),
+        };
+
+        commands.insert_resource(collection);
+    }
+}
+
+#[derive(Debug)]
+pub struct CarVehicles {
+    red: Model,
+    blue: Model,
+    yellow: Model,
+}
+
+impl CarVehicles {
+    pub fn get_random(&self, random_generator: &mut Rand32) -> &Model {
+        match random_generator.rand_range(0..3) {
+            0 => &self.red,
+            1 => &self.blue,
+            _ => &self.yellow,
+        }
+    }
+}
@@ -31,17 +31,55 @@ pub enum CurrentBiome {
     #[cfg(feature = "debug")]
     Default,
     CrossyValley,
+    Winter,
+    Moon,
     // Also, for level restarting
     #[default]
     None,
 }
 
-#[cfg(feature = "debug")]
 impl CurrentBiome {
+    /// Stable identifier of the biome for seeding random generators.
+    /// It must never change, otherwise the same seed will produce a different world.
+    pub fn seed_key(&self) -> u64 {
+        match self {
+            #[cfg(feature = "debug")]
+            CurrentBiome::Default => 0,
+            CurrentBiome::CrossyValley => 1,
+            CurrentBiome::Winter => 2,
+            CurrentBiome::Moon => 3,
+            CurrentBiome::None => u64::MAX,
+        }
+    }
+
+    /// Parses names like `crossy_valley`, which are used in settings.
+    pub fn from_name(name: &str) -> Option<CurrentBiome> {
+        match name {
+            #[cfg(feature = "debug")]
+            "default" => Some(CurrentBiome::Default),
+            "crossy_valley" => Some(CurrentBiome::CrossyValley),
+            "winter" => Some(CurrentBiome::Winter),
+            "moon" => Some(CurrentBiome::Moon),
+            _ => None,
+        }
+    }
+
+    /// Biomes that a run can cross into. Development biomes are never picked.
+    pub fn all_playable() -> Vec<CurrentBiome> {
+        vec![
+            CurrentBiome::CrossyValley,
+            CurrentBiome::Winter,
+            CurrentBiome::Moon,
+        ]
+    }
+
+    #[cfg(feature = "debug")]
     pub fn all_variant_names() -> Vec<(CurrentBiome, String)> {
         vec![
             (CurrentBiome::Default, "Default".into()),
             (CurrentBiome::CrossyValley, "Crossy Valley".into()),
+            (CurrentBiome::Winter, "Winter".into()),
+            (CurrentBiome::Moon, "Moon".into()),
             (CurrentBiome::None, "None".into()),
         ]
     }
@@ -53,6 +91,8 @@ impl fmt::Display for CurrentBiome {
         let biome_name = match self {
             CurrentBiome::Default => "Default",
             CurrentBiome::CrossyValley => "Crossy Valley",
+            CurrentBiome::Winter => "Winter",
+            CurrentBiome::Moon => "Moon",
             CurrentBiome::None => "None",
         };
 
@@ -1,6 +1,11 @@
+use std::env;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use bevy::math::Vec3;
 use bevy::prelude::{KeyCode, Mesh};
 use bevy::render::mesh::VertexAttributeValues;
+use oorandom::Rand32;
 
 use crate::constants::{
     PLAYER_MOVE_BACK_KEY_CODES, PLAYER_MOVE_FORWARD_KEY_CODES, PLAYER_MOVE_LEFT_KEY_CODES,
@@ -34,3 +39,125 @@ pub fn get_player_move_key_codes() -> Vec<KeyCode> {
     ]
     .concat()
 }
+
+/// Looks for a world seed in the `--seed` command line argument
+/// and then in the `CROSSY_SEED` environment variable.
+pub fn get_requested_seed() -> Option<u64> {
+    let seed = get_setting("seed", "CROSSY_SEED")?;
+
+    match seed.trim().parse() {
+        Ok(seed) => Some(seed),
+        Err(_) => {
+            warn!("Ignoring invalid world seed: {seed}");
+            None
+        }
+    }
+}
+
+/// Looks for a character name in the `--character` command line argument
+/// and then in the `CROSSY_CHARACTER` environment variable.
+pub fn get_requested_character() -> Option<String> {
+    get_setting("character", "CROSSY_CHARACTER").map(|name| name.trim().to_lowercase())
+}
+
+/// Looks for a biome name in the `--biome` command line argument
+/// and then in the `CROSSY_BIOME` environment variable.
+pub fn get_requested_biome() -> Option<String> {
+    get_setting("biome", "CROSSY_BIOME").map(|name| name.trim().to_lowercase())
+}
+
+/// Looks for a difficulty mode in the `--difficulty` command line argument
+/// and then in the `CROSSY_DIFFICULTY` environment variable.
+pub fn get_requested_difficulty() -> Option<String> {
+    get_setting("difficulty", "CROSSY_DIFFICULTY").map(|name| name.trim().to_lowercase())
+}
+
+/// Path of a save file in the directory from the `--data-dir` command line argument
+/// or the `CROSSY_DATA_DIR` environment variable, the working directory by default.
+pub fn get_save_path(file_name: &str) -> PathBuf {
+    get_setting("data-dir", "CROSSY_DATA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(file_name)
+}
+
+/// The command line argument takes precedence over the environment variable.
+fn get_setting(argument: &str, variable: &str) -> Option<String> {
+    get_argument(argument).or_else(|| env::var(variable).ok())
+}
+
+/// Reads either `--{argument} value` or `--{argument}=value` from the command line.
+/// The last one wins if the argument is repeated.
+pub fn get_argument(argument: &str) -> Option<String> {
+    let mut arguments = env::args().skip(1);
+    let mut value = None;
+
+    while let Some(next_argument) = arguments.next() {
+        let Some(name) = next_argument.strip_prefix("--") else {
+            continue;
+        };
+
+        if name == argument {
+            value = arguments.next();
+        } else if let Some(inline_value) = name
+            .strip_prefix(argument)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            value = Some(inline_value.into());
+        }
+    }
+
+    value
+}
+
+pub fn generate_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+/// Picks an item with a chance proportional to its weight.
+/// Returns `None` only if the sum of the weights is zero.
+pub fn pick_weighted<'a, T>(items: &'a [(T, u32)], random_generator: &mut Rand32) -> Option<&'a T> {
+    let total_weight = items.iter().map(|(_, weight)| weight).sum::<u32>();
+
+    if total_weight == 0 {
+        return None;
+    }
+
+    let mut number = random_generator.rand_range(0..total_weight);
+
+    for (item, weight) in items {
+        if number < *weight {
+            return Some(item);
+        }
+
+        number -= weight;
+    }
+
+    None
+}
+
+/// Mixes values into a single seed, so that similar values give unrelated seeds.
+/// Unlike the standard hashers, the result never changes between Rust versions and platforms.
+pub fn mix_seed(values: &[u64]) -> u64 {
+    values.iter().fold(0x9E37_79B9_7F4A_7C15, |state, value| {
+        split_mix(state ^ value)
+    })
+}
+
+/// FNV-1a hash, which is stable between Rust versions and platforms.
+pub fn hash_str(value: &str) -> u64 {
+    value.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+fn split_mix(value: u64) -> u64 {
+    let mut value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    value ^ (value >> 31)
+}
@@ -3,15 +3,18 @@ use bevy::prelude::{
     in_state, Commands, EventReader, IntoSystemConfigs, Mut, OnEnter, OnExit, Res, ResMut,
     Resource, World,
 };
-use bevy::utils::HashMap;
 
-use crate::constants::{MAP_MIN_X, PLAYER_SPAWN_POINT};
 use crate::events::RequestNewChunkSpawning;
 use crate::states::CurrentBiome;
 use crate::world::biomes::crossy_valley::regions::forest::GrassRegion;
+use crate::world::biomes::crossy_valley::regions::railway::RailwayRegion;
+use crate::world::biomes::crossy_valley::regions::road::RoadRegion;
 use crate::world::biomes::crossy_valley::regions::spawn_point::SpawnPointRegion;
-use crate::world::biomes::crossy_valley::regions::{forest, spawn_point, Region};
-use crate::world::biomes::StandardBiomeSystems;
+use crate::world::biomes::crossy_valley::regions::{
+    forest, railway, river, road, spawn_point, Region,
+};
+use crate::world::biomes::{StandardBiomeData, StandardBiomeSystems};
+use crate::world::difficulty::Difficulty;
 use crate::world::Map;
 
 mod regions;
@@ -22,7 +25,7 @@ pub struct CrossyValleyBiome;
 
 impl Plugin for CrossyValleyBiome {
     fn build(&self, app: &mut App) {
-        app.add_plugins((SpawnPointRegion, GrassRegion))
+        app.add_plugins((SpawnPointRegion, GrassRegion, RoadRegion, RailwayRegion))
             .add_systems(
                 OnEnter(CURRENT_BIOME),
                 (StandardBiomeSystems::enter_biome, Self::enter_biome),
@@ -30,7 +33,7 @@ impl Plugin for CrossyValleyBiome {
             .add_systems(
                 Update,
                 (
-                    Self::spawn_new_chunk,
+                    (Self::spawn_new_chunk, StandardBiomeSystems::switch_biome).chain(),
                     StandardBiomeSystems::despawn_old_chunk,
                 )
                     .distributive_run_if(in_state(CURRENT_BIOME)),
@@ -45,16 +48,15 @@ impl Plugin for CrossyValleyBiome {
 impl CrossyValleyBiome {
     fn enter_biome(world: &mut World) {
         let grass_region = forest::SystemIds::register(world);
-        let mut biome_data = BiomeData {
-            regions: HashMap::new(),
+        let biome_data = BiomeData {
+            standard: StandardBiomeData::register(world, CURRENT_BIOME),
             spawn_point_region: spawn_point::SystemIds::register(world, grass_region.spawn_ground),
             grass_region,
+            road_region: road::SystemIds::register(world),
+            river_region: river::SystemIds::register(world),
+            railway_region: railway::SystemIds::register(world),
         };
 
-        (MAP_MIN_X..PLAYER_SPAWN_POINT.x as i32 + 2).for_each(|x| {
-            biome_data.regions.insert(x, Region::SpawnPoint);
-        });
-
         world.insert_resource(biome_data);
     }
 
@@ -63,23 +65,14 @@ impl CrossyValleyBiome {
         mut new_chunk_spawning_requests: EventReader<RequestNewChunkSpawning>,
         mut biome_data: ResMut<BiomeData>,
         map: Res<Map>,
+        difficulty: Res<Difficulty>,
     ) {
-        let from_x = match map.chunks.is_empty() {
-            true => MAP_MIN_X,
-            false => match map.chunks.back() {
-                Some(chunk) => chunk.position_x + 1,
-                None => MAP_MIN_X,
-            },
-        };
-        let range = from_x..(from_x + new_chunk_spawning_requests.len() as i32);
-
-        // Please don't ask why we first insert a value and then immediately remove it.
-        // It's a trick for the future.
-        for x in range {
-            let region = biome_data.regions.entry(x).or_insert(Region::Forest);
+        let range = map.chunks_to_spawn(new_chunk_spawning_requests.len());
+        let regions = biome_data.standard.take_regions(range, &difficulty);
 
+        for (x, region) in regions {
             match region {
-                Region::SpawnPoint => {
+                Region::SpawnPoint | Region::Transition => {
                     commands.run_system_with_input(biome_data.spawn_point_region.spawn_ground, x);
                     commands
                         .run_system_with_input(biome_data.spawn_point_region.spawn_obstacles, x);
@@ -88,9 +81,21 @@ impl CrossyValleyBiome {
                     commands.run_system_with_input(biome_data.grass_region.spawn_ground, x);
                     commands.run_system_with_input(biome_data.grass_region.spawn_obstacles, x);
                 }
+                Region::Road => {
+                    commands.run_system_with_input(biome_data.road_region.spawn_ground, x);
+                    commands.run_system_with_input(biome_data.road_region.spawn_vehicles, x);
+                }
+                Region::River => {
+                    commands.run_system_with_input(biome_data.river_region.spawn_ground, x);
+                    commands.run_system_with_input(biome_data.river_region.spawn_logs, x);
+                }
+                Region::Railway => {
+                    commands.run_system_with_input(biome_data.railway_region.spawn_ground, x);
+                    commands.run_system_with_input(biome_data.railway_region.spawn_crossing, x);
+                }
             };
 
-            biome_data.regions.remove(&x);
+            biome_data.standard.spawn_standard(&mut commands, x, region);
         }
 
         new_chunk_spawning_requests.clear();
@@ -100,6 +105,10 @@ impl CrossyValleyBiome {
         world.resource_scope(|world, biome_data: Mut<BiomeData>| {
             biome_data.spawn_point_region.unregister(world);
             biome_data.grass_region.unregister(world);
+            biome_data.road_region.unregister(world);
+            biome_data.river_region.unregister(world);
+            biome_data.railway_region.unregister(world);
+            biome_data.standard.unregister(world);
         });
 
         world.remove_resource::<BiomeData>();
@@ -108,7 +117,10 @@ impl CrossyValleyBiome {
 
 #[derive(Resource)]
 struct BiomeData {
-    regions: HashMap<i32, Region>,
+    standard: StandardBiomeData<Region>,
     spawn_point_region: spawn_point::SystemIds,
     grass_region: forest::SystemIds,
+    road_region: road::SystemIds,
+    river_region: river::SystemIds,
+    railway_region: railway::SystemIds,
 }
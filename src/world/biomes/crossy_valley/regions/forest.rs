@@ -1,12 +1,15 @@
 use bevy::app::{App, Plugin};
 use bevy::ecs::system::SystemId;
+use bevy::math::IVec2;
 use bevy::prelude::{Commands, In, Res, ResMut, World};
 
-use crate::constants::{MAP_GAMEPLAY_MAX_Z, MAP_GAMEPLAY_MIN_Z, MAP_MAX_Z, MAP_MIN_Z};
-use crate::resources::grounds::GroundCollection;
 use crate::resources::obstacles::ObstacleCollection;
-use crate::world::biomes::{spawn_ground, spawn_obstacle};
-use crate::world::{Chunk, Map};
+use crate::world::biomes::crossy_valley::regions::Region;
+use crate::world::biomes::crossy_valley::{BiomeData, CURRENT_BIOME};
+use crate::world::biomes::{spawn_random_obstacle, GroundSpawner};
+use crate::world::difficulty::Difficulty;
+use crate::world::pool::EntityPool;
+use crate::world::Map;
 
 pub(in super::super) struct GrassRegion;
 
@@ -32,65 +35,37 @@ impl SystemIds {
         world.remove_system(self.spawn_obstacles).unwrap();
     }
 
-    fn spawn_ground(
-        In(x): In<i32>,
-        mut commands: Commands,
-        mut map: ResMut<Map>,
-        grounds: Res<GroundCollection>,
-    ) {
-        let mut entities = Vec::new();
-
-        for z in MAP_MIN_Z..MAP_MAX_Z {
-            let cube = if x % 2 == 0 {
-                match (MAP_GAMEPLAY_MIN_Z..=MAP_GAMEPLAY_MAX_Z).contains(&z) {
-                    true => &grounds.light_cube.default,
-                    false => &grounds.light_cube.dimmed,
-                }
-            } else {
-                match (MAP_GAMEPLAY_MIN_Z..=MAP_GAMEPLAY_MAX_Z).contains(&z) {
-                    true => &grounds.dark_cube.default,
-                    false => &grounds.dark_cube.dimmed,
-                }
-            };
-            let ground = spawn_ground(&mut commands, cube, x, z);
-
-            entities.push(ground);
-        }
-
-        map.chunks.push_back(Chunk {
-            position_x: x,
-            entities,
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, _| match x % 2 == 0 {
+            true => &palette.light_cube,
+            false => &palette.dark_cube,
         });
     }
 
     fn spawn_obstacles(
         In(x): In<i32>,
         mut commands: Commands,
+        mut pool: ResMut<EntityPool>,
         mut map: ResMut<Map>,
         obstacles: Res<ObstacleCollection>,
+        biome_data: Res<BiomeData>,
+        difficulty: Res<Difficulty>,
     ) {
-        for z in MAP_MIN_Z..MAP_MAX_Z {
-            if z == 0 {
-                continue;
-            }
-
-            let (model, rotation_factor) = match map.random_generator.rand_range(0..101) {
-                number if number <= 15 => (
-                    obstacles.trees.get_random(&mut map.random_generator),
-                    map.random_generator.rand_range(1..3) as f32,
-                ),
-                number if number <= 20 => (
-                    &obstacles.boulder,
-                    map.random_generator.rand_range(0..4) as f32 / 2.,
-                ),
-                number if number <= 21 => (
-                    &obstacles.stump,
-                    map.random_generator.rand_range(0..4) as f32 / 2.,
-                ),
-                _ => continue,
-            };
+        let weights = difficulty
+            .at(x)
+            .obstacle_weights(biome_data.standard.tables.obstacles_of(Region::Forest));
+        let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "obstacles");
 
-            spawn_obstacle(&mut commands, &mut map, model, x, z, rotation_factor);
+        for z in map.config.gameplay_z_range() {
+            spawn_random_obstacle(
+                &mut commands,
+                &mut pool,
+                &mut map,
+                &obstacles,
+                &weights,
+                &mut random_generator,
+                IVec2::new(x, z),
+            );
         }
     }
 }
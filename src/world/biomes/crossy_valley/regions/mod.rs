@@ -1,8 +1,38 @@
+use crate::world::biomes::StandardRegion;
+
 pub(super) mod forest;
+pub(super) mod railway;
+pub(super) mod river;
+pub(super) mod road;
 pub(super) mod spawn_point;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub(super) enum Region {
     SpawnPoint,
+    /// Calm strip between the previous biome and this one
+    Transition,
     Forest,
+    Road,
+    River,
+    Railway,
+}
+
+impl StandardRegion for Region {
+    const SPAWN_POINT: Self = Self::SpawnPoint;
+    const TRANSITION: Self = Self::Transition;
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "SpawnPoint" => Some(Self::SpawnPoint),
+            "Forest" => Some(Self::Forest),
+            "Road" => Some(Self::Road),
+            "River" => Some(Self::River),
+            "Railway" => Some(Self::Railway),
+            _ => None,
+        }
+    }
+
+    fn is_hazard(self) -> bool {
+        matches!(self, Self::Road | Self::River | Self::Railway)
+    }
 }
@@ -0,0 +1,389 @@
+use std::time::Duration;
+
+use bevy::app::{App, Plugin, Update};
+use bevy::ecs::system::SystemId;
+use bevy::hierarchy::BuildChildren;
+use bevy::pbr::{PbrBundle, StandardMaterial};
+use bevy::prelude::{
+    in_state, Commands, Component, Entity, EventWriter, Handle, In, IntoSystemConfigs, Query, Res,
+    ResMut, Transform, Visibility, With, World,
+};
+use bevy::time::Time;
+
+use crate::constants::{
+    PLAYER_HITBOX_HALF_SIZE, RAILWAY_MAX_IDLE_DURATION, RAILWAY_MIN_IDLE_DURATION,
+    RAILWAY_SIGNAL_BLINK_INTERVAL, RAILWAY_WARNING_DURATION, TRAIN_SPEED,
+};
+use crate::events::RequestPlayerDeath;
+use crate::player::{Player, PlayerDeathCause};
+use crate::resources::railways::RailwayCollection;
+use crate::resources::vehicles::VehicleCollection;
+use crate::states::AppState;
+use crate::world::biomes::crossy_valley::CURRENT_BIOME;
+use crate::world::biomes::GroundSpawner;
+use crate::world::difficulty::Difficulty;
+use crate::world::Map;
+
+pub(in super::super) struct RailwayRegion;
+
+impl Plugin for RailwayRegion {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (run_crossings, kill_player_on_contact)
+                .chain()
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
+
+pub(in super::super) struct SystemIds {
+    pub spawn_ground: SystemId<i32>,
+    pub spawn_crossing: SystemId<i32>,
+}
+
+impl SystemIds {
+    pub fn register(world: &mut World) -> Self {
+        Self {
+            spawn_ground: world.register_system(Self::spawn_ground),
+            spawn_crossing: world.register_system(Self::spawn_crossing),
+        }
+    }
+
+    pub fn unregister(&self, world: &mut World) {
+        world.remove_system(self.spawn_ground).unwrap();
+        world.remove_system(self.spawn_crossing).unwrap();
+    }
+
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, _| &palette.gravel_cube);
+    }
+
+    /// Spawns a signal pole next to the gameplay strip and a hidden train,
+    /// which is shown only while passing through the map.
+    fn spawn_crossing(
+        In(x): In<i32>,
+        mut commands: Commands,
+        mut map: ResMut<Map>,
+        railways: Res<RailwayCollection>,
+        vehicles: Res<VehicleCollection>,
+        difficulty: Res<Difficulty>,
+    ) {
+        let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "crossing");
+
+        let idle_range = RAILWAY_MAX_IDLE_DURATION - RAILWAY_MIN_IDLE_DURATION;
+        let idle_duration =
+            RAILWAY_MIN_IDLE_DURATION + idle_range.mul_f32(random_generator.rand_float());
+        let direction = match random_generator.rand_range(0..2) {
+            0 => -1.,
+            _ => 1.,
+        };
+        let timetable = TrainTimetable {
+            idle_duration,
+            warning_duration: RAILWAY_WARNING_DURATION,
+            train_velocity: TRAIN_SPEED * difficulty.at(x).train_speed * direction,
+            train_length: vehicles.train.mesh_size.z,
+            track_min_z: map.config.min_z,
+            track_max_z: map.config.max_z,
+        };
+
+        let train = commands
+            .spawn(PbrBundle {
+                mesh: vehicles.train.mesh.clone_weak(),
+                material: vehicles.train.material.clone_weak(),
+                transform: Transform::from_xyz(
+                    x as f32,
+                    0.5 + vehicles.train.mesh_size.y / 2.,
+                    map.config.min_z as f32,
+                ),
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            })
+            .id();
+
+        let pole_height = railways.signal_pole.mesh_size.y;
+        let mut lamp = None;
+        let mut pole = commands.spawn(PbrBundle {
+            mesh: railways.signal_pole.mesh.clone_weak(),
+            material: railways.signal_pole.material.clone_weak(),
+            transform: Transform::from_xyz(
+                x as f32,
+                0.5 + pole_height / 2.,
+                (map.config.gameplay_min_z - 1) as f32,
+            ),
+            ..Default::default()
+        });
+        pole.with_children(|builder| {
+            let lamp_entity = builder.spawn(PbrBundle {
+                mesh: railways.signal_lamp.off.mesh.clone_weak(),
+                material: railways.signal_lamp.off.material.clone_weak(),
+                transform: Transform::from_xyz(0., pole_height / 2., 0.),
+                ..Default::default()
+            });
+
+            lamp = Some(lamp_entity.id());
+        });
+
+        let Some(lamp) = lamp else {
+            return;
+        };
+
+        pole.insert(RailwayCrossing {
+            timetable,
+            elapsed: Duration::ZERO,
+            lamp,
+            train,
+        });
+        let pole = pole.id();
+
+        if let Some(chunk) = map
+            .chunks
+            .iter_mut()
+            .rev()
+            .find(|chunk| chunk.position_x == x)
+        {
+            chunk.entities.push(pole);
+            chunk.entities.push(train);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(in super::super) enum CrossingPhase {
+    Idle,
+    /// The signal is blinking, but the train has not appeared yet
+    Warning,
+    /// The train is on the map
+    Passing,
+}
+
+/// All timings of a single railway crossing cycle,
+/// so the crossing can be simulated without any entities.
+#[derive(Clone, Copy, Debug)]
+pub(in super::super) struct TrainTimetable {
+    pub idle_duration: Duration,
+    pub warning_duration: Duration,
+    /// Signed speed along the z axis in cells per second.
+    pub train_velocity: f32,
+    pub train_length: f32,
+    /// The train appears beyond one end of the track and disappears beyond the other one.
+    pub track_min_z: i32,
+    pub track_max_z: i32,
+}
+
+impl TrainTimetable {
+    /// Time from the train appearing at one map edge to leaving the map at the other one.
+    pub fn passing_duration(&self) -> Duration {
+        let distance = (self.track_max_z - self.track_min_z) as f32 + self.train_length;
+
+        Duration::from_secs_f32(distance / self.train_velocity.abs())
+    }
+
+    pub fn cycle_duration(&self) -> Duration {
+        self.idle_duration + self.warning_duration + self.passing_duration()
+    }
+
+    pub fn phase(&self, elapsed: Duration) -> CrossingPhase {
+        if elapsed < self.idle_duration {
+            CrossingPhase::Idle
+        } else if elapsed < self.idle_duration + self.warning_duration {
+            CrossingPhase::Warning
+        } else {
+            CrossingPhase::Passing
+        }
+    }
+
+    /// The signal is lit from the start of the warning until the train has left the map.
+    pub fn is_signal_lit(&self, elapsed: Duration) -> bool {
+        self.phase(elapsed) != CrossingPhase::Idle
+    }
+
+    /// The lamp blinks while the signal is lit.
+    pub fn is_lamp_on(&self, elapsed: Duration) -> bool {
+        if !self.is_signal_lit(elapsed) {
+            return false;
+        }
+
+        let blinks = (elapsed - self.idle_duration).as_secs_f32()
+            / RAILWAY_SIGNAL_BLINK_INTERVAL.as_secs_f32();
+
+        blinks % 2. < 1.
+    }
+
+    /// Position of the train centre along the z axis, if the train is on the map.
+    pub fn train_position_z(&self, elapsed: Duration) -> Option<f32> {
+        if self.phase(elapsed) != CrossingPhase::Passing {
+            return None;
+        }
+
+        let passing_time = (elapsed - self.idle_duration - self.warning_duration).as_secs_f32();
+        let start_z = match self.train_velocity > 0. {
+            true => self.track_min_z as f32 - self.train_length / 2.,
+            false => self.track_max_z as f32 + self.train_length / 2.,
+        };
+
+        Some(start_z + self.train_velocity * passing_time)
+    }
+}
+
+#[derive(Component)]
+pub(in super::super) struct RailwayCrossing {
+    pub timetable: TrainTimetable,
+    /// Time since the start of the current cycle.
+    pub elapsed: Duration,
+    lamp: Entity,
+    train: Entity,
+}
+
+impl RailwayCrossing {
+    pub fn tick(&mut self, delta: Duration) {
+        let cycle_duration = self.timetable.cycle_duration();
+
+        self.elapsed += delta;
+
+        while self.elapsed >= cycle_duration {
+            self.elapsed -= cycle_duration;
+        }
+    }
+}
+
+fn run_crossings(
+    time: Res<Time>,
+    railways: Res<RailwayCollection>,
+    mut crossings: Query<&mut RailwayCrossing>,
+    mut lamps: Query<&mut Handle<StandardMaterial>>,
+    mut trains: Query<(&mut Transform, &mut Visibility)>,
+) {
+    for mut crossing in crossings.iter_mut() {
+        crossing.tick(time.delta());
+
+        if let Ok(mut material) = lamps.get_mut(crossing.lamp) {
+            *material = match crossing.timetable.is_lamp_on(crossing.elapsed) {
+                true => railways.signal_lamp.on.material.clone_weak(),
+                false => railways.signal_lamp.off.material.clone_weak(),
+            };
+        }
+
+        if let Ok((mut transform, mut visibility)) = trains.get_mut(crossing.train) {
+            match crossing.timetable.train_position_z(crossing.elapsed) {
+                Some(z) => {
+                    transform.translation.z = z;
+                    *visibility = Visibility::Inherited;
+                }
+                None => *visibility = Visibility::Hidden,
+            }
+        }
+    }
+}
+
+fn kill_player_on_contact(
+    mut player_death_requester: EventWriter<RequestPlayerDeath>,
+    crossings: Query<(&RailwayCrossing, &Transform)>,
+    players: Query<&Transform, With<Player>>,
+) {
+    let Some(player_transform) = players.iter().next() else {
+        return;
+    };
+    let player_translation = player_transform.translation;
+
+    let is_hit = crossings.iter().any(|(crossing, transform)| {
+        let Some(train_z) = crossing.timetable.train_position_z(crossing.elapsed) else {
+            return false;
+        };
+
+        (transform.translation.x - player_translation.x).abs() < 0.5
+            && (train_z - player_translation.z).abs()
+                < crossing.timetable.train_length / 2. + PLAYER_HITBOX_HALF_SIZE
+    });
+
+    if is_hit {
+        player_death_requester.send(RequestPlayerDeath::new(PlayerDeathCause::Train));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::constants::{RAILWAY_WARNING_DURATION, TRAIN_SPEED};
+    use crate::world::MapConfig;
+
+    use super::TrainTimetable;
+
+    const STEP: Duration = Duration::from_millis(5);
+
+    fn timetable(config: &MapConfig, direction: f32) -> TrainTimetable {
+        TrainTimetable {
+            idle_duration: Duration::from_secs(3),
+            warning_duration: RAILWAY_WARNING_DURATION,
+            train_velocity: TRAIN_SPEED * direction,
+            train_length: 8.,
+            track_min_z: config.min_z,
+            track_max_z: config.max_z,
+        }
+    }
+
+    #[test]
+    fn signal_stays_lit_for_the_whole_warning() {
+        let config = MapConfig::default();
+        let timetable = timetable(&config, 1.);
+        let warning_end = timetable.idle_duration + timetable.warning_duration;
+
+        let mut elapsed = Duration::ZERO;
+        while elapsed < timetable.cycle_duration() {
+            let is_after_idle = elapsed >= timetable.idle_duration;
+
+            // Lit through the whole warning and for as long as the train is passing
+            assert_eq!(
+                timetable.is_signal_lit(elapsed),
+                is_after_idle,
+                "{elapsed:?}"
+            );
+            if elapsed < warning_end {
+                assert_eq!(timetable.train_position_z(elapsed), None, "{elapsed:?}");
+            }
+
+            elapsed += STEP;
+        }
+    }
+
+    #[test]
+    fn train_reaches_the_gameplay_strip_after_the_warning() {
+        let config = MapConfig::default();
+
+        for direction in [-1., 1.] {
+            let timetable = timetable(&config, direction);
+            let mut lit_since = None;
+            let mut has_reached_strip = false;
+
+            let mut elapsed = Duration::ZERO;
+            while elapsed < timetable.cycle_duration() {
+                if timetable.is_signal_lit(elapsed) {
+                    lit_since.get_or_insert(elapsed);
+                }
+
+                if let Some(z) = timetable.train_position_z(elapsed) {
+                    let front_z = z + timetable.train_length / 2. * direction;
+                    let back_z = z - timetable.train_length / 2. * direction;
+                    let (min_z, max_z) = (front_z.min(back_z), front_z.max(back_z));
+
+                    if max_z >= config.gameplay_min_z as f32
+                        && min_z <= config.gameplay_max_z as f32
+                    {
+                        has_reached_strip = true;
+
+                        let lit_since = lit_since.expect("The train reached the strip unannounced");
+                        assert!(
+                            elapsed - lit_since >= timetable.warning_duration,
+                            "{elapsed:?}"
+                        );
+                    }
+                }
+
+                elapsed += STEP;
+            }
+
+            assert!(has_reached_strip);
+        }
+    }
+}
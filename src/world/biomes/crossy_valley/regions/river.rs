@@ -0,0 +1,76 @@
+use bevy::ecs::system::SystemId;
+use bevy::math::Vec2;
+use bevy::prelude::{Commands, In, Res, ResMut, World};
+
+use crate::constants::{LOG_MAX_SPEED, LOG_MIN_SPEED};
+use crate::resources::platforms::PlatformCollection;
+use crate::world::biomes::crossy_valley::CURRENT_BIOME;
+use crate::world::biomes::{spawn_log, GroundSpawner};
+use crate::world::carriers::Carrier;
+use crate::world::Map;
+
+pub(in super::super) struct SystemIds {
+    pub spawn_ground: SystemId<i32>,
+    pub spawn_logs: SystemId<i32>,
+}
+
+impl SystemIds {
+    pub fn register(world: &mut World) -> Self {
+        Self {
+            spawn_ground: world.register_system(Self::spawn_ground),
+            spawn_logs: world.register_system(Self::spawn_logs),
+        }
+    }
+
+    pub fn unregister(&self, world: &mut World) {
+        world.remove_system(self.spawn_ground).unwrap();
+        world.remove_system(self.spawn_logs).unwrap();
+    }
+
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, _| &palette.water_cube);
+    }
+
+    /// Like roads, every river chunk is a single stream with its own direction and speed.
+    fn spawn_logs(
+        In(x): In<i32>,
+        mut commands: Commands,
+        mut map: ResMut<Map>,
+        platforms: Res<PlatformCollection>,
+    ) {
+        let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "logs");
+
+        let direction = match random_generator.rand_range(0..2) {
+            0 => -1.,
+            _ => 1.,
+        };
+        let speed = LOG_MIN_SPEED + random_generator.rand_float() * (LOG_MAX_SPEED - LOG_MIN_SPEED);
+        let count = random_generator.rand_range(2..4);
+        let offset = random_generator.rand_float();
+
+        let mut entities = Vec::new();
+
+        for index in 0..count {
+            let model = platforms.logs.get_random(&mut random_generator);
+            let carrier = Carrier {
+                velocity: speed * direction,
+                footprint: Vec2::new(model.mesh_size.x, model.mesh_size.z),
+            };
+
+            let stream_length = (map.config.max_z - map.config.min_z) as f32 + carrier.footprint.y;
+            let z = map.config.min_z as f32 - carrier.footprint.y / 2.
+                + (offset + index as f32 / count as f32) % 1. * stream_length;
+
+            entities.push(spawn_log(&mut commands, model, x, z, carrier));
+        }
+
+        if let Some(chunk) = map
+            .chunks
+            .iter_mut()
+            .rev()
+            .find(|chunk| chunk.position_x == x)
+        {
+            chunk.entities.append(&mut entities);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use bevy::app::{App, Plugin, Update};
+use bevy::ecs::system::SystemId;
+use bevy::prelude::{
+    in_state, Commands, EventWriter, In, IntoSystemConfigs, Query, Res, ResMut, Transform, With,
+    World,
+};
+use bevy::time::Time;
+
+use crate::constants::{PLAYER_HITBOX_HALF_SIZE, VEHICLE_MAX_SPEED, VEHICLE_MIN_SPEED};
+use crate::events::RequestPlayerDeath;
+use crate::player::{Player, PlayerDeathCause};
+use crate::resources::vehicles::VehicleCollection;
+use crate::states::AppState;
+use crate::world::biomes::crossy_valley::CURRENT_BIOME;
+use crate::world::biomes::{drift_along_z, spawn_vehicle, GroundSpawner, Vehicle};
+use crate::world::difficulty::Difficulty;
+use crate::world::Map;
+
+pub(in super::super) struct RoadRegion;
+
+impl Plugin for RoadRegion {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (move_vehicles, kill_player_on_contact)
+                .chain()
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
+
+pub(in super::super) struct SystemIds {
+    pub spawn_ground: SystemId<i32>,
+    pub spawn_vehicles: SystemId<i32>,
+}
+
+impl SystemIds {
+    pub fn register(world: &mut World) -> Self {
+        Self {
+            spawn_ground: world.register_system(Self::spawn_ground),
+            spawn_vehicles: world.register_system(Self::spawn_vehicles),
+        }
+    }
+
+    pub fn unregister(&self, world: &mut World) {
+        world.remove_system(self.spawn_ground).unwrap();
+        world.remove_system(self.spawn_vehicles).unwrap();
+    }
+
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, _| &palette.asphalt_cube);
+    }
+
+    /// Every road chunk is a single lane,
+    /// so all vehicles of the chunk move in the same direction at the same speed.
+    fn spawn_vehicles(
+        In(x): In<i32>,
+        mut commands: Commands,
+        mut map: ResMut<Map>,
+        vehicles: Res<VehicleCollection>,
+        difficulty: Res<Difficulty>,
+    ) {
+        let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "vehicles");
+        let speed_factor = difficulty.at(x).vehicle_speed;
+
+        let direction = match random_generator.rand_range(0..2) {
+            0 => -1.,
+            _ => 1.,
+        };
+        let is_truck_lane = random_generator.rand_range(0..4) == 0;
+        let (speed, count) = match is_truck_lane {
+            true => (VEHICLE_MIN_SPEED, random_generator.rand_range(1..3)),
+            false => (
+                VEHICLE_MIN_SPEED
+                    + random_generator.rand_float() * (VEHICLE_MAX_SPEED - VEHICLE_MIN_SPEED),
+                random_generator.rand_range(1..4),
+            ),
+        };
+        let offset = random_generator.rand_float();
+
+        let mut entities = Vec::new();
+
+        for index in 0..count {
+            let model = match is_truck_lane {
+                true => &vehicles.truck,
+                false => vehicles.cars.get_random(&mut random_generator),
+            };
+            let vehicle = Vehicle {
+                velocity: speed * speed_factor * direction,
+                length: model.mesh_size.z,
+            };
+
+            let lane_length = (map.config.max_z - map.config.min_z) as f32 + vehicle.length;
+            let z = map.config.min_z as f32 - vehicle.length / 2.
+                + (offset + index as f32 / count as f32) % 1. * lane_length;
+
+            entities.push(spawn_vehicle(&mut commands, model, x, z, vehicle));
+        }
+
+        if let Some(chunk) = map
+            .chunks
+            .iter_mut()
+            .rev()
+            .find(|chunk| chunk.position_x == x)
+        {
+            chunk.entities.append(&mut entities);
+        }
+    }
+}
+
+fn move_vehicles(time: Res<Time>, map: Res<Map>, mut vehicles: Query<(&Vehicle, &mut Transform)>) {
+    for (vehicle, mut transform) in vehicles.iter_mut() {
+        drift_along_z(
+            &map.config,
+            &mut transform.translation,
+            vehicle.velocity,
+            vehicle.length,
+            time.delta_seconds(),
+        );
+    }
+}
+
+fn kill_player_on_contact(
+    mut player_death_requester: EventWriter<RequestPlayerDeath>,
+    vehicles: Query<(&Vehicle, &Transform)>,
+    players: Query<&Transform, With<Player>>,
+) {
+    let Some(player_transform) = players.iter().next() else {
+        return;
+    };
+    let player_translation = player_transform.translation;
+
+    let is_hit = vehicles.iter().any(|(vehicle, transform)| {
+        (transform.translation.x - player_translation.x).abs() < 0.5
+            && (transform.translation.z - player_translation.z).abs()
+                < vehicle.length / 2. + PLAYER_HITBOX_HALF_SIZE
+    });
+
+    if is_hit {
+        player_death_requester.send(RequestPlayerDeath::new(PlayerDeathCause::Vehicle));
+    }
+}
@@ -1,10 +1,13 @@
 use bevy::app::{App, Plugin};
 use bevy::ecs::system::SystemId;
+use bevy::math::IVec2;
 use bevy::prelude::{Commands, In, Res, ResMut, World};
 
-use crate::constants::{MAP_GAMEPLAY_MAX_Z, MAP_GAMEPLAY_MIN_Z, MAP_MAX_Z, MAP_MIN_Z};
+use crate::resources::biomes::ObstacleKind;
 use crate::resources::obstacles::ObstacleCollection;
+use crate::world::biomes::crossy_valley::CURRENT_BIOME;
 use crate::world::biomes::spawn_obstacle;
+use crate::world::pool::EntityPool;
 use crate::world::Map;
 
 pub(in super::super) struct SpawnPointRegion;
@@ -33,18 +36,30 @@ impl SystemIds {
     fn spawn_obstacles(
         In(x): In<i32>,
         mut commands: Commands,
+        mut pool: ResMut<EntityPool>,
         mut map: ResMut<Map>,
         obstacles: Res<ObstacleCollection>,
     ) {
-        for z in MAP_MIN_Z..MAP_MAX_Z {
-            if x > -4 && (MAP_GAMEPLAY_MIN_Z..MAP_GAMEPLAY_MAX_Z + 1).contains(&z) {
-                continue;
-            }
+        // Only the strip behind the spawn point is blocked, the sides are left to the scenery
+        if x > -4 {
+            return;
+        }
+
+        let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "obstacles");
 
-            let model = obstacles.trees.get_random(&mut map.random_generator);
-            let rotation_factor = map.random_generator.rand_range(1..3) as f32;
+        for z in map.config.gameplay_z_range() {
+            let model = obstacles.trees.get_random(&mut random_generator);
+            let rotation_factor = random_generator.rand_range(1..3) as f32;
 
-            spawn_obstacle(&mut commands, &mut map, model, x, z, rotation_factor);
+            spawn_obstacle(
+                &mut commands,
+                &mut pool,
+                &mut map,
+                ObstacleKind::Tree,
+                model,
+                IVec2::new(x, z),
+                rotation_factor,
+            );
         }
     }
 }
@@ -7,7 +7,6 @@ use bevy::prelude::{
 };
 use bevy::utils::HashMap;
 
-use crate::constants::MAP_MIN_X;
 use crate::events::{RequestNewChunkSpawning, RequestOldChunkDespawning};
 use crate::states::CurrentBiome;
 use crate::world::biomes::default::regions::meadow::GrassRegion;
@@ -38,7 +37,7 @@ impl Plugin for DefaultBiome {
             .add_systems(
                 Update,
                 (
-                    Self::spawn_new_chunk,
+                    (Self::spawn_new_chunk, StandardBiomeSystems::switch_biome).chain(),
                     StandardBiomeSystems::despawn_old_chunk,
                 )
                     .distributive_run_if(in_state(CURRENT_BIOME)),
@@ -72,14 +71,8 @@ impl DefaultBiome {
         mut biome_data: ResMut<BiomeData>,
         map: Res<Map>,
     ) {
-        let from_x = match map.chunks.is_empty() {
-            true => MAP_MIN_X,
-            false => match map.chunks.back() {
-                Some(chunk) => chunk.position_x + 1,
-                None => MAP_MIN_X,
-            },
-        };
-        let range = from_x..(from_x + new_chunk_spawning_requests.len() as i32);
+        // Chunks after a planned transition belong to the next biome
+        let range = map.chunks_to_spawn(new_chunk_spawning_requests.len());
 
         for x in range {
             let region = biome_data.regions.entry(x).or_insert(Region::Meadow);
@@ -106,8 +99,9 @@ impl DefaultBiome {
     ) {
     }
 
-    /// Should run when [CurrentBiome] enters {current biome variant}.
-    /// This is where we cleanse the world of the current biome.
+    /// Should run when [CurrentBiome] exits {current biome variant}.
+    /// This is where we clean up the biome, but not its chunks,
+    /// because they stay on the map if the run continues in the next biome.
     fn leave_biome(world: &mut World) {
         world.resource_scope(|world, biome_data: Mut<BiomeData>| {
             biome_data.grass_region.unregister(world);
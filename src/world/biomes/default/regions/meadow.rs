@@ -1,11 +1,9 @@
 use bevy::app::{App, Plugin};
 use bevy::ecs::system::SystemId;
-use bevy::prelude::{Commands, In, Res, ResMut, World};
+use bevy::prelude::{In, World};
 
-use crate::constants::{MAP_GAMEPLAY_MAX_Z, MAP_GAMEPLAY_MIN_Z, MAP_MAX_Z, MAP_MIN_Z};
-use crate::resources::grounds::GroundCollection;
-use crate::world::biomes::spawn_ground;
-use crate::world::{Chunk, Map};
+use crate::world::biomes::default::CURRENT_BIOME;
+use crate::world::biomes::GroundSpawner;
 
 /// Plugin containing region-specific systems, resources, etc.
 pub(in super::super) struct GrassRegion;
@@ -35,34 +33,10 @@ impl SystemIds {
         world.remove_system(self.spawn_ground).unwrap();
     }
 
-    fn spawn_ground(
-        In(x): In<i32>,
-        mut commands: Commands,
-        mut map: ResMut<Map>,
-        grounds: Res<GroundCollection>,
-    ) {
-        let mut entities = Vec::new();
-
-        for z in MAP_MIN_Z..MAP_MAX_Z {
-            let cube = if x % 2 == 0 {
-                match (MAP_GAMEPLAY_MIN_Z..=MAP_GAMEPLAY_MAX_Z).contains(&z) {
-                    true => &grounds.light_cube.default,
-                    false => &grounds.light_cube.dimmed,
-                }
-            } else {
-                match (MAP_GAMEPLAY_MIN_Z..=MAP_GAMEPLAY_MAX_Z).contains(&z) {
-                    true => &grounds.dark_cube.default,
-                    false => &grounds.dark_cube.dimmed,
-                }
-            };
-            let ground = spawn_ground(&mut commands, cube, x, z);
-
-            entities.push(ground);
-        }
-
-        map.chunks.push_back(Chunk {
-            position_x: x,
-            entities,
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, _| match x % 2 == 0 {
+            true => &palette.light_cube,
+            false => &palette.dark_cube,
         });
     }
 
@@ -1,61 +1,236 @@
+use std::collections::VecDeque;
 use std::f32::consts::PI;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Range;
 
+use bevy::ecs::system::{SystemId, SystemParam};
 use bevy::hierarchy::DespawnRecursiveExt;
 use bevy::math::{IVec2, Quat, Vec3};
 use bevy::pbr::{CascadeShadowConfigBuilder, DirectionalLight, DirectionalLightBundle, PbrBundle};
 use bevy::prelude::{
-    Color, Commands, Component, Entity, EventReader, Or, Query, ResMut, Transform, With,
+    Assets, Commands, Component, Entity, EventReader, In, Mesh, NextState, Query, Res, ResMut,
+    State, Transform, Visibility, With, World,
 };
+use bevy::utils::{HashMap, HashSet};
 use bevy_rapier3d::dynamics::RigidBody;
-use bevy_rapier3d::geometry::Collider;
+use bevy_rapier3d::geometry::{Collider, ColliderDisabled};
+use oorandom::Rand32;
 
-use crate::constants::{MAP_MAX_Z, MAP_MIN_Z};
+use crate::coins::Coin;
+use crate::constants::{BIOME_TRANSITION_STRIP_LENGTH, PLAYER_SPAWN_POINT};
 use crate::events::RequestOldChunkDespawning;
+use crate::resources::biomes::{
+    to_color, BiomeCollection, BiomeDefinition, DecorationKind, ObstacleKind, RegionTables,
+    SceneryKind,
+};
+use crate::resources::coins::CoinCollection;
+use crate::resources::decorations::DecorationCollection;
+use crate::resources::grounds::{Ground, GroundCollection, Grounds};
+use crate::resources::obstacles::ObstacleCollection;
+use crate::resources::scenery::SceneryCollection;
 use crate::resources::Model;
-use crate::world::Map;
+use crate::states::CurrentBiome;
+use crate::utils;
+use crate::world::biomes::scheduler::RegionScheduler;
+use crate::world::carriers::Carrier;
+use crate::world::difficulty::Difficulty;
+use crate::world::pool::{EntityPool, PoolKind, Pooled};
+use crate::world::tiles::TileObstacle;
+use crate::world::{Chunk, Map, MapConfig};
 
 pub mod crossy_valley;
 #[cfg(feature = "debug")]
 pub mod default;
+pub mod moon;
+mod scheduler;
+pub mod winter;
+
+/// Regions of a biome that are planned ahead by a [RegionScheduler].
+trait StandardRegion: Copy + Debug + Eq + Hash {
+    /// A fresh run starts with it. It's never planned, but its rule must lead to the first run.
+    const SPAWN_POINT: Self;
+    /// Calm strip between the previous biome and this one, decorated like the spawn point.
+    const TRANSITION: Self;
+
+    /// Region of a name in the biome definition.
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// Hazardous regions get more likely as the difficulty grows.
+    fn is_hazard(self) -> bool;
+}
+
+/// What every biome with [StandardRegion]s keeps while it's entered:
+/// the planned regions, the tables of the regions and the standard systems run on every chunk.
+struct StandardBiomeData<R> {
+    /// Regions planned for chunks that haven't been spawned yet.
+    regions: HashMap<i32, R>,
+    region_scheduler: RegionScheduler<R>,
+    tables: RegionTables<R>,
+    ensure_traversable_chunk: SystemId<i32>,
+    spawn_coin: SystemId<(i32, u32)>,
+    spawn_scenery: SystemId<(i32, Vec<(SceneryKind, u32)>)>,
+    spawn_decorations: SystemId<(i32, Vec<(DecorationKind, u32)>)>,
+}
+
+impl<R: StandardRegion> StandardBiomeData<R> {
+    /// Reads the rules and the tables of the regions from the definition of the `biome`.
+    /// A fresh run starts with the spawn point, a biome entered mid-run with a transition strip.
+    fn register(world: &mut World, biome: CurrentBiome) -> Self {
+        let (rules, tables) = world
+            .resource::<BiomeCollection>()
+            .get(biome, world.resource::<Assets<BiomeDefinition>>())
+            // A missing definition is reported by [StandardBiomeSystems::enter_biome],
+            // the first region then repeats
+            .map(|definition| {
+                (
+                    scheduler::parse_rules(definition, R::from_name),
+                    RegionTables::parse(definition, R::from_name),
+                )
+            })
+            .unwrap_or_default();
+
+        let map = world.resource::<Map>();
+        let start_x = map.next_chunk_x();
+        let (first_region, first_region_end_x) = match map.chunks.is_empty() {
+            true => (R::SPAWN_POINT, PLAYER_SPAWN_POINT.x as i32 + 2),
+            false => (R::TRANSITION, start_x + BIOME_TRANSITION_STRIP_LENGTH),
+        };
+        let region_scheduler = RegionScheduler::new(
+            rules,
+            map.chunk_random_generator(biome, start_x, "regions"),
+            R::SPAWN_POINT,
+            first_region_end_x,
+        );
+
+        Self {
+            regions: (start_x..first_region_end_x)
+                .map(|x| (x, first_region))
+                .collect(),
+            region_scheduler,
+            tables,
+            ensure_traversable_chunk: world
+                .register_system(StandardBiomeSystems::ensure_traversable_chunk),
+            spawn_coin: world.register_system(StandardBiomeSystems::spawn_coin),
+            spawn_scenery: world.register_system(StandardBiomeSystems::spawn_scenery),
+            spawn_decorations: world.register_system(StandardBiomeSystems::spawn_decorations),
+        }
+    }
+
+    fn unregister(&self, world: &mut World) {
+        world.remove_system(self.ensure_traversable_chunk).unwrap();
+        world.remove_system(self.spawn_coin).unwrap();
+        world.remove_system(self.spawn_scenery).unwrap();
+        world.remove_system(self.spawn_decorations).unwrap();
+    }
+
+    /// Plans the regions through the end of the `range` and takes out the region of every chunk.
+    fn take_regions(&mut self, range: Range<i32>, difficulty: &Difficulty) -> Vec<(i32, R)> {
+        self.region_scheduler
+            .plan_until(range.end - 1, &mut self.regions, |region, weight, x| {
+                difficulty.at(x).region_weight(weight, region.is_hazard())
+            });
+
+        range
+            .filter_map(|x| Some((x, self.regions.remove(&x)?)))
+            .collect()
+    }
+
+    /// Should be invoked after the systems of the region have spawned the ground of the chunk.
+    fn spawn_standard(&self, commands: &mut Commands, x: i32, region: R) {
+        let decorated_region = match region == R::TRANSITION {
+            true => R::SPAWN_POINT,
+            false => region,
+        };
+
+        if let Some(weights) = self.tables.scenery.get(&decorated_region) {
+            commands.run_system_with_input(self.spawn_scenery, (x, weights.clone()));
+        }
+
+        // The area behind the spawn point is blocked on purpose
+        if region != R::SPAWN_POINT {
+            commands.run_system_with_input(self.ensure_traversable_chunk, x);
+        }
+
+        if let Some(chance) = self.tables.coin_chances.get(&region) {
+            commands.run_system_with_input(self.spawn_coin, (x, *chance));
+        }
+
+        if let Some(weights) = self.tables.decorations.get(&decorated_region) {
+            commands.run_system_with_input(self.spawn_decorations, (x, weights.clone()));
+        }
+    }
+}
 
 struct StandardBiomeSystems;
 
 impl StandardBiomeSystems {
-    fn enter_biome(mut commands: Commands) {
-        commands.spawn(DirectionalLightBundle {
-            directional_light: DirectionalLight {
-                illuminance: 7_000.,
-                shadows_enabled: true,
-                ..Default::default()
-            },
-            transform: Transform::from_translation(Vec3::new(0., 5.5, -3.))
-                .looking_at(Vec3::ZERO, Vec3::Y),
-            cascade_shadow_config: CascadeShadowConfigBuilder {
-                first_cascade_far_bound: 7.,
-                minimum_distance: 0.,
-                maximum_distance: 0.1,
+    /// Spawns the lighting and colours the grounds as described in the biome definition.
+    /// Also plans the transition to the next biome.
+    fn enter_biome(
+        mut commands: Commands,
+        mut grounds: ResMut<GroundCollection>,
+        mut map: ResMut<Map>,
+        current_biome: Res<State<CurrentBiome>>,
+        biomes: Res<BiomeCollection>,
+        definitions: Res<Assets<BiomeDefinition>>,
+    ) {
+        let next_chunk_x = map.next_chunk_x();
+        map.plan_next_biome(*current_biome.get(), next_chunk_x);
+
+        let Some(definition) = biomes.get(*current_biome.get(), &definitions) else {
+            warn!("No definition for the {:?} biome", current_biome.get());
+            return;
+        };
+
+        for light in &definition.lighting {
+            let mut light_bundle = DirectionalLightBundle {
+                directional_light: DirectionalLight {
+                    color: to_color(light.color),
+                    illuminance: light.illuminance,
+                    shadows_enabled: light.shadows_enabled,
+                    ..Default::default()
+                },
+                transform: Transform::from_translation(Vec3::from(light.translation))
+                    .looking_at(Vec3::ZERO, Vec3::Y),
                 ..Default::default()
+            };
+
+            if light.shadows_enabled {
+                light_bundle.cascade_shadow_config = CascadeShadowConfigBuilder {
+                    first_cascade_far_bound: 7.,
+                    minimum_distance: 0.,
+                    maximum_distance: 0.1,
+                    ..Default::default()
+                }
+                .into();
             }
-            .into(),
-            ..Default::default()
-        });
 
-        commands.spawn(DirectionalLightBundle {
-            directional_light: DirectionalLight {
-                color: Color::rgb(219. / 255., 220. / 255., 1.),
-                illuminance: 3_000.,
-                ..Default::default()
-            },
-            transform: Transform::from_translation(Vec3::new(-3., 2., 1.5))
-                .looking_at(Vec3::ZERO, Vec3::Y),
-            ..Default::default()
-        });
+            commands.spawn(light_bundle);
+        }
+
+        // Later biomes of the run take over the physics only once the player reaches them
+        if map.chunks.is_empty() {
+            commands.insert_resource(definition.physics.clone());
+        }
+
+        grounds.insert_palette(*current_biome.get(), &definition.ground_palette);
+    }
+
+    /// Hands spawning over to the next biome once the current one has spawned its last chunk.
+    fn switch_biome(mut current_biome_setter: ResMut<NextState<CurrentBiome>>, map: Res<Map>) {
+        if let Some((transition_x, next_biome)) = map.next_biome {
+            if map.next_chunk_x() >= transition_x {
+                current_biome_setter.set(next_biome);
+            }
+        }
     }
 
     fn despawn_old_chunk(
         mut commands: Commands,
         mut old_chunk_despawning_requests: EventReader<RequestOldChunkDespawning>,
         mut map: ResMut<Map>,
+        pooled: Query<&Pooled>,
     ) {
         for _ in old_chunk_despawning_requests.read() {
             let Some(chunk) = map.chunks.front() else {
@@ -64,16 +239,12 @@ impl StandardBiomeSystems {
             let chunk_position_x = chunk.position_x;
 
             for entity in &chunk.entities {
-                if let Some(entity) = commands.get_entity(*entity) {
-                    entity.despawn_recursive();
-                }
+                EntityPool::release(&mut commands, *entity, &pooled);
             }
 
-            for z in MAP_MIN_Z..MAP_MAX_Z {
-                if let Some(entity) = map.obstacles_xz.remove(&IVec2::new(chunk_position_x, z)) {
-                    if let Some(entity) = commands.get_entity(entity) {
-                        entity.despawn_recursive();
-                    }
+            for tile in map.tiles.remove_rows_through(chunk_position_x) {
+                if let Some(obstacle) = tile.obstacle {
+                    EntityPool::release(&mut commands, obstacle.entity, &pooled);
                 }
             }
 
@@ -81,66 +252,595 @@ impl StandardBiomeSystems {
         }
     }
 
-    fn leave_biome(
+    /// Should be invoked after all other spawning systems of a chunk,
+    /// except for chunks that are intentionally blocked, like the area behind the spawn point.
+    ///
+    /// If the player can't get from the previous chunk to this one, the obstacle in front of
+    /// a random reachable cell of the previous row is removed, which is enough to let them through.
+    fn ensure_traversable_chunk(
+        In(x): In<i32>,
         mut commands: Commands,
         mut map: ResMut<Map>,
-        entities: Query<Entity, Or<(With<DirectionalLight>, With<Ground>, With<Obstacle>)>>,
+        pooled: Query<&Pooled>,
     ) {
-        for entity in &entities {
-            if let Some(entity) = commands.get_entity(entity) {
-                entity.despawn_recursive();
+        let Some(biome) = map
+            .chunks
+            .iter()
+            .rev()
+            .find(|chunk| chunk.position_x == x)
+            .map(|chunk| chunk.biome)
+        else {
+            return;
+        };
+        let previous_reachable_z = match map.reachable_row.take() {
+            Some((reachable_x, reachable_z)) if reachable_x == x - 1 => reachable_z,
+            _ => map
+                .tiles
+                .tiles_in_row(x - 1)
+                .filter(|(z, tile)| map.config.is_gameplay_z(*z) && tile.obstacle.is_none())
+                .map(|(z, _)| z)
+                .collect(),
+        };
+
+        let mut reachable_z = find_reachable_z(&map, x, &previous_reachable_z);
+
+        if reachable_z.is_empty() && !previous_reachable_z.is_empty() {
+            let mut candidates = previous_reachable_z.iter().copied().collect::<Vec<_>>();
+            candidates.sort();
+
+            let mut random_generator = map.chunk_random_generator(biome, x, "traversal");
+            let index = random_generator.rand_range(0..candidates.len() as u32) as usize;
+            let obstacle = map
+                .tiles
+                .get_mut(x, candidates[index])
+                .and_then(|tile| tile.obstacle.take());
+
+            if let Some(obstacle) = obstacle {
+                EntityPool::release(&mut commands, obstacle.entity, &pooled);
             }
+
+            reachable_z = find_reachable_z(&map, x, &previous_reachable_z);
+        }
+
+        map.reachable_row = Some((x, reachable_z));
+    }
+
+    /// Places a coin on one of the reachable cells of the chunk with `chance` percent.
+    /// Should be invoked after [Self::ensure_traversable_chunk], which finds these cells.
+    fn spawn_coin(
+        In((x, chance)): In<(i32, u32)>,
+        mut commands: Commands,
+        mut map: ResMut<Map>,
+        coins: Res<CoinCollection>,
+    ) {
+        let Some(biome) = map
+            .chunks
+            .iter()
+            .rev()
+            .find(|chunk| chunk.position_x == x)
+            .map(|chunk| chunk.biome)
+        else {
+            return;
+        };
+        let mut random_generator = map.chunk_random_generator(biome, x, "coins");
+
+        if random_generator.rand_range(0..100) >= chance {
+            return;
+        }
+
+        let mut candidates = match &map.reachable_row {
+            Some((reachable_x, reachable_z)) if *reachable_x == x => reachable_z
+                .iter()
+                .copied()
+                .filter(|z| {
+                    map.tiles
+                        .get(x, *z)
+                        .is_some_and(|tile| !tile.kind.is_lethal() && tile.coin.is_none())
+                })
+                .collect::<Vec<_>>(),
+            _ => return,
+        };
+
+        if candidates.is_empty() {
+            return;
+        }
+
+        candidates.sort();
+
+        let z = candidates[random_generator.rand_range(0..candidates.len() as u32) as usize];
+        let entity = spawn_coin(&mut commands, &coins.coin, x, z);
+
+        if let Some(tile) = map.tiles.get_mut(x, z) {
+            tile.coin = Some(entity);
+        }
+
+        if let Some(chunk) = map
+            .chunks
+            .iter_mut()
+            .rev()
+            .find(|chunk| chunk.position_x == x)
+        {
+            chunk.entities.push(entity);
         }
+    }
+
+    /// Decorates the cells outside the gameplay strip with props from the `weights` table.
+    /// The props are only kept in the chunk, so they never block the player or the traversal checks.
+    fn spawn_scenery(
+        In((x, weights)): In<(i32, Vec<(SceneryKind, u32)>)>,
+        mut commands: Commands,
+        mut pool: ResMut<EntityPool>,
+        mut map: ResMut<Map>,
+        obstacles: Res<ObstacleCollection>,
+        scenery: Res<SceneryCollection>,
+    ) {
+        let Some(chunk_index) = map.chunks.iter().rposition(|chunk| chunk.position_x == x) else {
+            return;
+        };
+        let mut random_generator =
+            map.chunk_random_generator(map.chunks[chunk_index].biome, x, "scenery");
+
+        let config = map.config;
+        let entities = config
+            .z_range()
+            .filter(|z| !config.is_gameplay_z(*z))
+            .filter_map(|z| {
+                spawn_random_scenery(
+                    &mut commands,
+                    &mut pool,
+                    &obstacles,
+                    &scenery,
+                    &weights,
+                    &mut random_generator,
+                    IVec2::new(x, z),
+                )
+            })
+            .collect::<Vec<_>>();
 
-        map.chunks.clear();
-        map.obstacles_xz.clear();
+        map.chunks[chunk_index].entities.extend(entities);
+    }
+
+    /// Puts decorations from the `weights` table on the free cells of the gameplay strip.
+    /// Should be invoked after [Self::spawn_coin], so the decorations don't hide the coins.
+    ///
+    /// The decorations are only kept in the chunk, the tiles stay walkable.
+    fn spawn_decorations(
+        In((x, weights)): In<(i32, Vec<(DecorationKind, u32)>)>,
+        mut commands: Commands,
+        mut pool: ResMut<EntityPool>,
+        mut map: ResMut<Map>,
+        decorations: Res<DecorationCollection>,
+    ) {
+        let Some(chunk_index) = map.chunks.iter().rposition(|chunk| chunk.position_x == x) else {
+            return;
+        };
+        let mut random_generator =
+            map.chunk_random_generator(map.chunks[chunk_index].biome, x, "decorations");
+
+        let entities = map
+            .tiles
+            .tiles_in_row(x)
+            .filter(|(z, tile)| {
+                map.config.is_gameplay_z(*z)
+                    && tile.obstacle.is_none()
+                    && tile.coin.is_none()
+                    && !tile.kind.is_lethal()
+            })
+            .filter_map(|(z, _)| {
+                spawn_random_decoration(
+                    &mut commands,
+                    &mut pool,
+                    &decorations,
+                    &weights,
+                    &mut random_generator,
+                    IVec2::new(x, z),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        map.chunks[chunk_index].entities.extend(entities);
+    }
+
+    /// Chunks are left on the map, since the next biome may continue the same run.
+    /// They are despawned either as old chunks or when the world is cleared.
+    fn leave_biome(mut commands: Commands, lights: Query<Entity, With<DirectionalLight>>) {
+        for entity in lights.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
     }
 }
 
-fn spawn_ground(commands: &mut Commands, model: &Model, x: i32, z: i32) -> Entity {
+/// Searches the gameplay cells of the `x` row that the player can reach
+/// from the reachable cells of the previous row.
+/// The player is allowed to step back to the previous row to get around obstacles.
+fn find_reachable_z(map: &Map, x: i32, previous_reachable_z: &HashSet<i32>) -> HashSet<i32> {
+    let is_free = |position: &IVec2| {
+        (x - 1..=x).contains(&position.x)
+            && map.config.is_gameplay_z(position.y)
+            && map.tiles.is_walkable(position.x, position.y)
+    };
+
+    let mut visited = previous_reachable_z
+        .iter()
+        .map(|z| IVec2::new(x - 1, *z))
+        .collect::<HashSet<_>>();
+    let mut queue = visited.iter().copied().collect::<VecDeque<_>>();
+
+    while let Some(position) = queue.pop_front() {
+        for offset in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+            let next_position = position + offset;
+
+            if is_free(&next_position) && visited.insert(next_position) {
+                queue.push_back(next_position);
+            }
+        }
+    }
+
+    visited
+        .into_iter()
+        .filter(|position| position.x == x)
+        .map(|position| position.y)
+        .collect()
+}
+
+/// Everything needed to spawn the ground of a chunk, shared by the `spawn_ground` systems of all regions.
+#[derive(SystemParam)]
+struct GroundSpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    pool: ResMut<'w, EntityPool>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    map: ResMut<'w, Map>,
+    grounds: ResMut<'w, GroundCollection>,
+}
+
+impl GroundSpawner<'_, '_> {
+    /// Starts the chunk at `x` with the whole ground row spawned as a single entity
+    /// with one mesh and one collider, and fills the row of the tile grid with the kinds of the grounds.
+    /// `ground_of` picks the ground of every cell from the palette of the biome,
+    /// it's dimmed outside the gameplay strip.
+    ///
+    /// Takes an entity from the pool if there is one, so only its mesh and position change.
+    /// Rows with the same colours share their mesh.
+    fn spawn_chunk(
+        &mut self,
+        biome: CurrentBiome,
+        x: i32,
+        ground_of: impl for<'g> Fn(&'g Grounds, i32) -> &'g Ground,
+    ) {
+        let palette = *self.grounds.get(biome);
+        self.map
+            .tiles
+            .insert_row(x, |z| ground_of(&palette, z).kind);
+
+        let config = self.map.config;
+        let colors = config
+            .z_range()
+            .map(|z| match config.is_gameplay_z(z) {
+                true => ground_of(&palette, z).default,
+                false => ground_of(&palette, z).dimmed,
+            })
+            .collect::<Vec<_>>();
+        let mesh = self.grounds.row_mesh(&mut self.meshes, &colors);
+        let transform =
+            Transform::from_xyz(x as f32, 0., (config.min_z + config.max_z - 1) as f32 / 2.);
+        let collider = Collider::cuboid(0.5, 0.5, colors.len() as f32 / 2.);
+
+        let ground = match self.pool.acquire(PoolKind::Ground) {
+            Some(entity) => {
+                self.commands
+                    .entity(entity)
+                    .insert((mesh, transform, Visibility::Inherited, collider))
+                    .remove::<ColliderDisabled>();
+
+                entity
+            }
+            None => self
+                .commands
+                .spawn((
+                    PbrBundle {
+                        mesh,
+                        material: self.grounds.material.clone_weak(),
+                        transform,
+                        ..Default::default()
+                    },
+                    GroundRow,
+                    Pooled(PoolKind::Ground),
+                    RigidBody::Fixed,
+                    collider,
+                ))
+                .id(),
+        };
+
+        self.map.chunks.push_back(Chunk {
+            position_x: x,
+            biome,
+            entities: vec![ground],
+        });
+    }
+}
+
+/// Stands the model on the centre of the cell, turned by `rotation_factor` half-turns.
+fn cell_transform(model: &Model, position: IVec2, rotation_factor: f32) -> Transform {
+    Transform::from_xyz(
+        position.x as f32,
+        0.5 + model.mesh_size.y / 2.,
+        position.y as f32,
+    )
+    .with_rotation(Quat::from_rotation_y(rotation_factor * PI))
+}
+
+/// Takes an entity of the `pool_kind` from the pool if there is one,
+/// otherwise spawns a new one with the `marker`.
+fn spawn_pooled_model(
+    commands: &mut Commands,
+    pool: &mut EntityPool,
+    pool_kind: PoolKind,
+    marker: impl Component,
+    model: &Model,
+    transform: Transform,
+) -> Entity {
+    match pool.acquire(pool_kind) {
+        Some(entity) => commands
+            .entity(entity)
+            .insert((
+                model.mesh.clone_weak(),
+                model.material.clone_weak(),
+                transform,
+                Visibility::Inherited,
+            ))
+            .remove::<ColliderDisabled>()
+            .id(),
+        None => commands
+            .spawn((
+                PbrBundle {
+                    mesh: model.mesh.clone_weak(),
+                    material: model.material.clone_weak(),
+                    transform,
+                    ..Default::default()
+                },
+                marker,
+                Pooled(pool_kind),
+            ))
+            .id(),
+    }
+}
+
+fn spawn_obstacle(
+    commands: &mut Commands,
+    pool: &mut EntityPool,
+    map: &mut ResMut<Map>,
+    kind: ObstacleKind,
+    model: &Model,
+    position: IVec2,
+    rotation_factor: f32,
+) {
+    let entity = spawn_pooled_model(
+        commands,
+        pool,
+        PoolKind::Obstacle,
+        Obstacle,
+        model,
+        cell_transform(model, position, rotation_factor),
+    );
+
+    match map.tiles.get_mut(position.x, position.y) {
+        Some(tile) => tile.obstacle = Some(TileObstacle { kind, entity }),
+        None => warn!(
+            "Obstacle spawned outside the tile grid at {} {}",
+            position.x, position.y
+        ),
+    }
+}
+
+/// Picks an obstacle from the weight table, so nothing is spawned if the pick is empty.
+fn spawn_random_obstacle(
+    commands: &mut Commands,
+    pool: &mut EntityPool,
+    map: &mut ResMut<Map>,
+    obstacles: &ObstacleCollection,
+    weights: &[(ObstacleKind, u32)],
+    random_generator: &mut Rand32,
+    position: IVec2,
+) {
+    let Some(kind) = utils::pick_weighted(weights, random_generator).copied() else {
+        return;
+    };
+    let (model, rotation_factor) = match kind {
+        ObstacleKind::Tree => (
+            obstacles.trees.get_random(random_generator),
+            random_generator.rand_range(1..3) as f32,
+        ),
+        ObstacleKind::Boulder => (
+            &obstacles.boulder,
+            random_generator.rand_range(0..4) as f32 / 2.,
+        ),
+        ObstacleKind::Stump => (
+            &obstacles.stump,
+            random_generator.rand_range(0..4) as f32 / 2.,
+        ),
+        ObstacleKind::Empty => return,
+    };
+
+    spawn_obstacle(commands, pool, map, kind, model, position, rotation_factor);
+}
+
+/// Picks a prop from the scenery table. Unlike obstacles, it's never written to the tile grid.
+fn spawn_random_scenery(
+    commands: &mut Commands,
+    pool: &mut EntityPool,
+    obstacles: &ObstacleCollection,
+    scenery: &SceneryCollection,
+    weights: &[(SceneryKind, u32)],
+    random_generator: &mut Rand32,
+    position: IVec2,
+) -> Option<Entity> {
+    let kind = utils::pick_weighted(weights, random_generator).copied()?;
+    let (model, rotation_factor) = match kind {
+        SceneryKind::Tree => (
+            obstacles.trees.get_random(random_generator),
+            random_generator.rand_range(1..3) as f32,
+        ),
+        SceneryKind::Boulder => (
+            &obstacles.boulder,
+            random_generator.rand_range(0..4) as f32 / 2.,
+        ),
+        SceneryKind::Stump => (
+            &obstacles.stump,
+            random_generator.rand_range(0..4) as f32 / 2.,
+        ),
+        // Fences always run along the strip
+        SceneryKind::Fence => (&scenery.fence, 0.),
+        SceneryKind::Snowman => (&scenery.snowman, 0.),
+        SceneryKind::Crystal => (
+            &scenery.crystal,
+            random_generator.rand_range(0..4) as f32 / 8.,
+        ),
+        SceneryKind::Empty => return None,
+    };
+
+    Some(spawn_pooled_model(
+        commands,
+        pool,
+        PoolKind::Scenery,
+        Scenery,
+        model,
+        cell_transform(model, position, rotation_factor),
+    ))
+}
+
+/// Picks a decoration from the weight table and puts it somewhere on the cell.
+fn spawn_random_decoration(
+    commands: &mut Commands,
+    pool: &mut EntityPool,
+    decorations: &DecorationCollection,
+    weights: &[(DecorationKind, u32)],
+    random_generator: &mut Rand32,
+    position: IVec2,
+) -> Option<Entity> {
+    let model = match utils::pick_weighted(weights, random_generator).copied()? {
+        DecorationKind::Flower => &decorations.flower,
+        DecorationKind::Tuft => &decorations.tuft,
+        DecorationKind::Pebble => &decorations.pebble,
+        DecorationKind::Puddle => &decorations.puddle,
+        DecorationKind::Empty => return None,
+    };
+
+    // Puddles are the widest, so they are kept closer to the centre
+    let max_offset = (1. - model.mesh_size.x.max(model.mesh_size.z)) / 2.;
+    let offset_x = (random_generator.rand_float() * 2. - 1.) * max_offset;
+    let offset_z = (random_generator.rand_float() * 2. - 1.) * max_offset;
+    let rotation_factor = random_generator.rand_float();
+
+    let mut transform = cell_transform(model, position, rotation_factor);
+    transform.translation += Vec3::new(offset_x, 0., offset_z);
+
+    Some(spawn_pooled_model(
+        commands,
+        pool,
+        PoolKind::Decoration,
+        Decoration,
+        model,
+        transform,
+    ))
+}
+
+fn spawn_vehicle(
+    commands: &mut Commands,
+    model: &Model,
+    x: i32,
+    z: f32,
+    vehicle: Vehicle,
+) -> Entity {
+    let rotation_factor = match vehicle.velocity < 0. {
+        true => 1.,
+        false => 0.,
+    };
+
     commands
         .spawn((
             PbrBundle {
                 mesh: model.mesh.clone_weak(),
                 material: model.material.clone_weak(),
-                transform: Transform::from_xyz(x as f32, 0., z as f32),
+                transform: Transform::from_xyz(x as f32, 0.5 + model.mesh_size.y / 2., z)
+                    .with_rotation(Quat::from_rotation_y(rotation_factor * PI)),
                 ..Default::default()
             },
-            Ground,
-            RigidBody::Fixed,
-            Collider::cuboid(
-                model.mesh_size.x / 2.,
-                model.mesh_size.y / 2.,
-                model.mesh_size.z / 2.,
-            ),
+            vehicle,
         ))
         .id()
 }
 
-fn spawn_obstacle(
-    commands: &mut Commands,
-    map: &mut ResMut<Map>,
-    model: &Model,
-    x: i32,
-    z: i32,
-    rotation_factor: f32,
+/// Coins hover a little above the ground.
+fn spawn_coin(commands: &mut Commands, model: &Model, x: i32, z: i32) -> Entity {
+    commands
+        .spawn((
+            PbrBundle {
+                mesh: model.mesh.clone_weak(),
+                material: model.material.clone_weak(),
+                transform: Transform::from_xyz(x as f32, 0.75 + model.mesh_size.y / 2., z as f32),
+                ..Default::default()
+            },
+            Coin,
+        ))
+        .id()
+}
+
+/// Logs are half-submerged, so the player's feet are at the same height as on the ground.
+fn spawn_log(commands: &mut Commands, model: &Model, x: i32, z: f32, carrier: Carrier) -> Entity {
+    commands
+        .spawn((
+            PbrBundle {
+                mesh: model.mesh.clone_weak(),
+                material: model.material.clone_weak(),
+                transform: Transform::from_xyz(x as f32, 0.5, z),
+                ..Default::default()
+            },
+            carrier,
+        ))
+        .id()
+}
+
+/// Moves something along the z axis.
+/// Once it has completely left the map, it reappears on the opposite side.
+pub(super) fn drift_along_z(
+    config: &MapConfig,
+    translation: &mut Vec3,
+    velocity: f32,
+    length: f32,
+    delta_seconds: f32,
 ) {
-    let obstacle = commands.spawn((
-        PbrBundle {
-            mesh: model.mesh.clone_weak(),
-            material: model.material.clone_weak(),
-            transform: Transform::from_xyz(x as f32, 0.5 + model.mesh_size.y / 2., z as f32)
-                .with_rotation(Quat::from_rotation_y(rotation_factor * PI)),
-            ..Default::default()
-        },
-        Obstacle,
-    ));
+    let min_z = config.min_z as f32 - length / 2.;
+    let max_z = config.max_z as f32 + length / 2.;
 
-    map.obstacles_xz.insert(IVec2::new(x, z), obstacle.id());
+    translation.z += velocity * delta_seconds;
+
+    if translation.z > max_z {
+        translation.z -= max_z - min_z;
+    } else if translation.z < min_z {
+        translation.z += max_z - min_z;
+    }
 }
 
+/// Ground of all cells of a chunk.
 #[derive(Component)]
-pub struct Ground;
+pub struct GroundRow;
 
 #[derive(Component)]
 pub struct Obstacle;
+
+/// Decorates the cells outside the gameplay strip, pooled again together with its chunk.
+#[derive(Component)]
+pub struct Scenery;
+
+/// Lies on a walkable cell of the gameplay strip, pooled again together with its chunk.
+#[derive(Component)]
+pub struct Decoration;
+
+/// Anything that drives along the z axis and kills the player on contact.
+#[derive(Clone, Copy, Component)]
+pub struct Vehicle {
+    /// Signed speed along the z axis in cells per second.
+    pub velocity: f32,
+    /// Length of the vehicle along the z axis.
+    pub length: f32,
+}
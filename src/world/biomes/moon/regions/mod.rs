@@ -0,0 +1,32 @@
+use crate::world::biomes::StandardRegion;
+
+pub(super) mod highlands;
+pub(super) mod plains;
+pub(super) mod spawn_point;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub(super) enum Region {
+    SpawnPoint,
+    /// Calm strip between the previous biome and this one
+    Transition,
+    Plains,
+    Highlands,
+}
+
+impl StandardRegion for Region {
+    const SPAWN_POINT: Self = Self::SpawnPoint;
+    const TRANSITION: Self = Self::Transition;
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "SpawnPoint" => Some(Self::SpawnPoint),
+            "Plains" => Some(Self::Plains),
+            "Highlands" => Some(Self::Highlands),
+            _ => None,
+        }
+    }
+
+    fn is_hazard(self) -> bool {
+        matches!(self, Self::Highlands)
+    }
+}
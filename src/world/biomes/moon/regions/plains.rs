@@ -0,0 +1,65 @@
+use bevy::ecs::system::SystemId;
+use bevy::math::IVec2;
+use bevy::prelude::{Commands, In, Res, ResMut, World};
+
+use crate::resources::obstacles::ObstacleCollection;
+use crate::world::biomes::moon::regions::Region;
+use crate::world::biomes::moon::{BiomeData, CURRENT_BIOME};
+use crate::world::biomes::{spawn_random_obstacle, GroundSpawner};
+use crate::world::difficulty::Difficulty;
+use crate::world::pool::EntityPool;
+use crate::world::Map;
+
+pub(in super::super) struct SystemIds {
+    pub spawn_ground: SystemId<i32>,
+    pub spawn_obstacles: SystemId<i32>,
+}
+
+impl SystemIds {
+    pub fn register(world: &mut World) -> Self {
+        Self {
+            spawn_ground: world.register_system(Self::spawn_ground),
+            spawn_obstacles: world.register_system(Self::spawn_obstacles),
+        }
+    }
+
+    pub fn unregister(&self, world: &mut World) {
+        world.remove_system(self.spawn_ground).unwrap();
+        world.remove_system(self.spawn_obstacles).unwrap();
+    }
+
+    /// Lunar dust uses the grass colours of the biome palette.
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, _| match x % 2 == 0 {
+            true => &palette.light_cube,
+            false => &palette.dark_cube,
+        });
+    }
+
+    fn spawn_obstacles(
+        In(x): In<i32>,
+        mut commands: Commands,
+        mut pool: ResMut<EntityPool>,
+        mut map: ResMut<Map>,
+        obstacles: Res<ObstacleCollection>,
+        biome_data: Res<BiomeData>,
+        difficulty: Res<Difficulty>,
+    ) {
+        let weights = difficulty
+            .at(x)
+            .obstacle_weights(biome_data.standard.tables.obstacles_of(Region::Plains));
+        let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "obstacles");
+
+        for z in map.config.gameplay_z_range() {
+            spawn_random_obstacle(
+                &mut commands,
+                &mut pool,
+                &mut map,
+                &obstacles,
+                &weights,
+                &mut random_generator,
+                IVec2::new(x, z),
+            );
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+use bevy::utils::{HashMap, HashSet};
+use oorandom::Rand32;
+
+use crate::resources::biomes::BiomeDefinition;
+use crate::utils;
+
+/// Describes how long a run of a region is and which regions may follow it.
+#[derive(Clone, Debug)]
+pub struct RegionRule<R> {
+    /// Number of chunks in a single run of the region. Starts with at least one.
+    pub run_length: RangeInclusive<u32>,
+    /// Regions that may follow the run, with their weights.
+    /// The sum of the weights is never zero.
+    pub transitions: Vec<(R, u32)>,
+}
+
+/// Reads the rules of all regions from the biome definition.
+/// Regions that `parse_region` doesn't know are skipped with a warning.
+///
+/// The definitions are edited by hand, so broken rules are fixed up with a warning:
+/// invalid run lengths become a single chunk, transitions to regions without a rule are dropped
+/// and a region without any transition left repeats itself.
+pub fn parse_rules<R: Copy + Debug + Eq + Hash>(
+    definition: &BiomeDefinition,
+    parse_region: impl Fn(&str) -> Option<R>,
+) -> HashMap<R, RegionRule<R>> {
+    let mut rules = definition
+        .regions
+        .iter()
+        .filter_map(|(name, region)| {
+            let Some(key) = parse_region(name) else {
+                warn!("Skipping unknown region: {name}");
+                return None;
+            };
+            let transitions = region
+                .transitions
+                .iter()
+                .filter_map(|(name, weight)| match parse_region(name) {
+                    Some(region) => Some((region, *weight)),
+                    None => {
+                        warn!("Skipping unknown region transition: {name}");
+                        None
+                    }
+                })
+                .collect();
+            let run_length = match region.run_length {
+                (min, max) if 1 <= min && min <= max => min..=max,
+                run_length => {
+                    warn!("Invalid run length of {name}: {run_length:?}, using a single chunk");
+                    1..=1
+                }
+            };
+            let rule = RegionRule {
+                run_length,
+                transitions,
+            };
+
+            Some((key, rule))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let known_regions = rules.keys().copied().collect::<HashSet<_>>();
+
+    for (region, rule) in rules.iter_mut() {
+        rule.transitions.retain(|(next_region, _)| {
+            let is_known = known_regions.contains(next_region);
+            if !is_known {
+                warn!("Skipping transition from {region:?} to {next_region:?}, which has no rule");
+            }
+
+            is_known
+        });
+
+        if rule.transitions.iter().all(|(_, weight)| *weight == 0) {
+            warn!("No transitions from {region:?}, it repeats itself instead");
+            rule.transitions = vec![(*region, 1)];
+        }
+    }
+
+    rules
+}
+
+/// Plans runs of regions ahead of time, so that chunks know their region before they are spawned.
+///
+/// The scheduler only draws from its own random generator,
+/// so the same seed always produces the same sequence of regions.
+#[derive(Debug)]
+pub struct RegionScheduler<R> {
+    rules: HashMap<R, RegionRule<R>>,
+    random_generator: Rand32,
+    last_region: R,
+    next_x: i32,
+}
+
+impl<R: Copy + Debug + Eq + Hash> RegionScheduler<R> {
+    /// `last_region` is the region that has already been planned right before `next_x`.
+    pub fn new(
+        rules: HashMap<R, RegionRule<R>>,
+        random_generator: Rand32,
+        last_region: R,
+        next_x: i32,
+    ) -> Self {
+        if !rules.contains_key(&last_region) {
+            warn!("No rule for {last_region:?}, it repeats itself instead");
+        }
+
+        Self {
+            rules,
+            random_generator,
+            last_region,
+            next_x,
+        }
+    }
+
+    /// Plans whole runs of regions until `x` has its region.
+    /// `weight_of` may change the weight of a transition to the run that starts at the given x.
+    pub fn plan_until(
+        &mut self,
+        x: i32,
+        regions: &mut HashMap<i32, R>,
+        weight_of: impl Fn(R, u32, i32) -> u32,
+    ) {
+        while self.next_x <= x {
+            let region = self.pick_next_region(&weight_of);
+            let run_length = match self.rules.get(&region) {
+                Some(rule) => self
+                    .random_generator
+                    .rand_range(*rule.run_length.start()..*rule.run_length.end() + 1),
+                None => 1,
+            };
+
+            for _ in 0..run_length {
+                regions.insert(self.next_x, region);
+                self.next_x += 1;
+            }
+
+            self.last_region = region;
+        }
+    }
+
+    /// Stays in the last region if it has no rule, which only happens
+    /// when the region before the first run is missing from the definition.
+    fn pick_next_region(&mut self, weight_of: impl Fn(R, u32, i32) -> u32) -> R {
+        let Some(rule) = self.rules.get(&self.last_region) else {
+            return self.last_region;
+        };
+        let transitions = rule
+            .transitions
+            .iter()
+            .map(|(region, weight)| (*region, weight_of(*region, *weight, self.next_x)))
+            .collect::<Vec<_>>();
+
+        utils::pick_weighted(&transitions, &mut self.random_generator)
+            .copied()
+            .unwrap_or(self.last_region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::ron;
+    use bevy::utils::HashMap;
+    use oorandom::Rand32;
+
+    use crate::resources::biomes::BiomeDefinition;
+
+    use super::{parse_rules, RegionScheduler};
+
+    const BROKEN_DEFINITION: &str = r#"(
+        lighting: [],
+        ground_palette: (
+            light_grass: (0, 0, 0),
+            dark_grass: (0, 0, 0),
+            asphalt: (0, 0, 0),
+            water: (0, 0, 0),
+            gravel: (0, 0, 0),
+            ice: (0, 0, 0),
+        ),
+        regions: {
+            "A": (run_length: (0, 0), transitions: [("B", 1), ("C", 1)]),
+            "B": (run_length: (3, 1), transitions: [("D", 1)]),
+            "C": (run_length: (1, 2), transitions: [("A", 0)]),
+        },
+    )"#;
+
+    fn parse_region(name: &str) -> Option<char> {
+        name.chars().next()
+    }
+
+    #[test]
+    fn broken_rules_are_fixed_up() {
+        let definition: BiomeDefinition =
+            ron::de::from_str(BROKEN_DEFINITION).expect("Failed to parse the definition");
+        let rules = parse_rules(&definition, parse_region);
+
+        assert_eq!(rules[&'A'].run_length, 1..=1);
+        assert_eq!(rules[&'A'].transitions, vec![('B', 1), ('C', 1)]);
+        assert_eq!(rules[&'B'].run_length, 1..=1);
+        assert_eq!(rules[&'B'].transitions, vec![('B', 1)]);
+        assert_eq!(rules[&'C'].transitions, vec![('C', 1)]);
+    }
+
+    #[test]
+    fn broken_rules_plan_every_chunk() {
+        let definition: BiomeDefinition =
+            ron::de::from_str(BROKEN_DEFINITION).expect("Failed to parse the definition");
+
+        // 'S' has no rule, like a spawn point missing from the definition
+        for last_region in ['A', 'S'] {
+            let mut scheduler = RegionScheduler::new(
+                parse_rules(&definition, parse_region),
+                Rand32::new(1),
+                last_region,
+                0,
+            );
+            let mut regions = HashMap::new();
+
+            scheduler.plan_until(50, &mut regions, |_, weight, _| weight);
+
+            assert!((0..=50).all(|x| regions.contains_key(&x)));
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::{
+    in_state, Commands, EventReader, IntoSystemConfigs, Mut, OnEnter, OnExit, Res, ResMut,
+    Resource, World,
+};
+
+use crate::events::RequestNewChunkSpawning;
+use crate::states::CurrentBiome;
+use crate::world::biomes::winter::regions::{frozen_lake, snowfield, spawn_point, Region};
+use crate::world::biomes::{StandardBiomeData, StandardBiomeSystems};
+use crate::world::difficulty::Difficulty;
+use crate::world::Map;
+
+mod regions;
+
+const CURRENT_BIOME: CurrentBiome = CurrentBiome::Winter;
+
+/// Snowy biome without traffic, but with ice that makes the player slide.
+pub struct WinterBiome;
+
+impl Plugin for WinterBiome {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(CURRENT_BIOME),
+            (StandardBiomeSystems::enter_biome, Self::enter_biome),
+        )
+        .add_systems(
+            Update,
+            (
+                (Self::spawn_new_chunk, StandardBiomeSystems::switch_biome).chain(),
+                StandardBiomeSystems::despawn_old_chunk,
+            )
+                .distributive_run_if(in_state(CURRENT_BIOME)),
+        )
+        .add_systems(
+            OnExit(CURRENT_BIOME),
+            (StandardBiomeSystems::leave_biome, Self::leave_biome),
+        );
+    }
+}
+
+impl WinterBiome {
+    fn enter_biome(world: &mut World) {
+        let biome_data = BiomeData {
+            standard: StandardBiomeData::register(world, CURRENT_BIOME),
+            spawn_point_region: spawn_point::SystemIds::register(world),
+            snowfield_region: snowfield::SystemIds::register(world),
+            frozen_lake_region: frozen_lake::SystemIds::register(world),
+        };
+
+        world.insert_resource(biome_data);
+    }
+
+    fn spawn_new_chunk(
+        mut commands: Commands,
+        mut new_chunk_spawning_requests: EventReader<RequestNewChunkSpawning>,
+        mut biome_data: ResMut<BiomeData>,
+        map: Res<Map>,
+        difficulty: Res<Difficulty>,
+    ) {
+        let range = map.chunks_to_spawn(new_chunk_spawning_requests.len());
+        let regions = biome_data.standard.take_regions(range, &difficulty);
+
+        for (x, region) in regions {
+            match region {
+                Region::SpawnPoint | Region::Transition => {
+                    commands.run_system_with_input(biome_data.spawn_point_region.spawn_ground, x);
+                    commands
+                        .run_system_with_input(biome_data.spawn_point_region.spawn_obstacles, x);
+                }
+                Region::Snowfield => {
+                    commands.run_system_with_input(biome_data.snowfield_region.spawn_ground, x);
+                    commands.run_system_with_input(biome_data.snowfield_region.spawn_obstacles, x);
+                }
+                Region::FrozenLake => {
+                    commands.run_system_with_input(biome_data.frozen_lake_region.spawn_ground, x);
+                }
+            };
+
+            biome_data.standard.spawn_standard(&mut commands, x, region);
+        }
+
+        new_chunk_spawning_requests.clear();
+    }
+
+    fn leave_biome(world: &mut World) {
+        world.resource_scope(|world, biome_data: Mut<BiomeData>| {
+            biome_data.spawn_point_region.unregister(world);
+            biome_data.snowfield_region.unregister(world);
+            biome_data.frozen_lake_region.unregister(world);
+            biome_data.standard.unregister(world);
+        });
+
+        world.remove_resource::<BiomeData>();
+    }
+}
+
+#[derive(Resource)]
+struct BiomeData {
+    standard: StandardBiomeData<Region>,
+    spawn_point_region: spawn_point::SystemIds,
+    snowfield_region: snowfield::SystemIds,
+    frozen_lake_region: frozen_lake::SystemIds,
+}
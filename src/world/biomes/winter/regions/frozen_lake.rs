@@ -0,0 +1,31 @@
+use bevy::ecs::system::SystemId;
+use bevy::prelude::{In, World};
+
+use crate::world::biomes::winter::regions::snowfield::snow_ground_of;
+use crate::world::biomes::winter::CURRENT_BIOME;
+use crate::world::biomes::GroundSpawner;
+
+pub(in super::super) struct SystemIds {
+    pub spawn_ground: SystemId<i32>,
+}
+
+impl SystemIds {
+    pub fn register(world: &mut World) -> Self {
+        Self {
+            spawn_ground: world.register_system(Self::spawn_ground),
+        }
+    }
+
+    pub fn unregister(&self, world: &mut World) {
+        world.remove_system(self.spawn_ground).unwrap();
+    }
+
+    /// The whole gameplay strip is frozen, the shores are covered with snow.
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        let config = ground_spawner.map.config;
+
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, z| {
+            snow_ground_of(palette, x, config.is_gameplay_z(z))
+        });
+    }
+}
@@ -0,0 +1,32 @@
+use crate::world::biomes::StandardRegion;
+
+pub(super) mod frozen_lake;
+pub(super) mod snowfield;
+pub(super) mod spawn_point;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub(super) enum Region {
+    SpawnPoint,
+    /// Calm strip between the previous biome and this one
+    Transition,
+    Snowfield,
+    FrozenLake,
+}
+
+impl StandardRegion for Region {
+    const SPAWN_POINT: Self = Self::SpawnPoint;
+    const TRANSITION: Self = Self::Transition;
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "SpawnPoint" => Some(Self::SpawnPoint),
+            "Snowfield" => Some(Self::Snowfield),
+            "FrozenLake" => Some(Self::FrozenLake),
+            _ => None,
+        }
+    }
+
+    fn is_hazard(self) -> bool {
+        matches!(self, Self::FrozenLake)
+    }
+}
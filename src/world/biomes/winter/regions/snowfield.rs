@@ -0,0 +1,115 @@
+use bevy::ecs::system::SystemId;
+use bevy::math::IVec2;
+use bevy::prelude::{Commands, In, Res, ResMut, World};
+
+use crate::resources::biomes::ObstacleKind;
+use crate::resources::grounds::{Ground, Grounds};
+use crate::resources::obstacles::ObstacleCollection;
+use crate::world::biomes::winter::regions::Region;
+use crate::world::biomes::winter::{BiomeData, CURRENT_BIOME};
+use crate::world::biomes::{spawn_random_obstacle, GroundSpawner};
+use crate::world::difficulty::Difficulty;
+use crate::world::pool::EntityPool;
+use crate::world::Map;
+
+pub(in super::super) struct SystemIds {
+    pub spawn_ground: SystemId<i32>,
+    pub spawn_obstacles: SystemId<i32>,
+}
+
+impl SystemIds {
+    pub fn register(world: &mut World) -> Self {
+        Self {
+            spawn_ground: world.register_system(Self::spawn_ground),
+            spawn_obstacles: world.register_system(Self::spawn_obstacles),
+        }
+    }
+
+    pub fn unregister(&self, world: &mut World) {
+        world.remove_system(self.spawn_ground).unwrap();
+        world.remove_system(self.spawn_obstacles).unwrap();
+    }
+
+    /// Some chunks have a single ice patch that covers a part of the gameplay strip.
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        let mut random_generator =
+            ground_spawner
+                .map
+                .chunk_random_generator(CURRENT_BIOME, x, "ice");
+        let config = ground_spawner.map.config;
+
+        let patch = match random_generator.rand_range(0..3) {
+            0 => {
+                let length = random_generator.rand_range(2..6) as i32;
+                // The strip can be a single cell wide, and a patch can start on any of its cells
+                let width = (config.gameplay_max_z - config.gameplay_min_z + 1) as u32;
+                let start_z = config.gameplay_min_z + random_generator.rand_range(0..width) as i32;
+
+                // Never spills into the dimmed cells outside the strip
+                start_z..(start_z + length).min(config.gameplay_max_z + 1)
+            }
+            _ => 0..0,
+        };
+
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, z| {
+            snow_ground_of(palette, x, patch.contains(&z))
+        });
+    }
+
+    /// Nothing stands on ice, so the player always has somewhere to slide.
+    fn spawn_obstacles(
+        In(x): In<i32>,
+        mut commands: Commands,
+        mut pool: ResMut<EntityPool>,
+        mut map: ResMut<Map>,
+        obstacles: Res<ObstacleCollection>,
+        biome_data: Res<BiomeData>,
+        difficulty: Res<Difficulty>,
+    ) {
+        let weights = difficulty
+            .at(x)
+            .obstacle_weights(biome_data.standard.tables.obstacles_of(Region::Snowfield));
+        spawn_random_obstacles(&mut commands, &mut pool, &mut map, &obstacles, &weights, x);
+    }
+}
+
+/// Alternating snow rows, or ice where `is_ice` is true.
+pub(super) fn snow_ground_of(palette: &Grounds, x: i32, is_ice: bool) -> &Ground {
+    match (is_ice, x % 2 == 0) {
+        (true, _) => &palette.ice_cube,
+        (false, true) => &palette.light_cube,
+        (false, false) => &palette.dark_cube,
+    }
+}
+
+/// Obstacles are picked from the weight table, but never placed on ice.
+fn spawn_random_obstacles(
+    commands: &mut Commands,
+    pool: &mut EntityPool,
+    map: &mut ResMut<Map>,
+    obstacles: &ObstacleCollection,
+    weights: &[(ObstacleKind, u32)],
+    x: i32,
+) {
+    let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "obstacles");
+
+    for z in map.config.gameplay_z_range() {
+        if map
+            .tiles
+            .get(x, z)
+            .is_some_and(|tile| tile.kind.is_slippery())
+        {
+            continue;
+        }
+
+        spawn_random_obstacle(
+            commands,
+            pool,
+            map,
+            obstacles,
+            weights,
+            &mut random_generator,
+            IVec2::new(x, z),
+        );
+    }
+}
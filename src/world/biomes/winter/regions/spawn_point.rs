@@ -0,0 +1,67 @@
+use bevy::ecs::system::SystemId;
+use bevy::math::IVec2;
+use bevy::prelude::{Commands, In, Res, ResMut, World};
+
+use crate::resources::biomes::ObstacleKind;
+use crate::resources::obstacles::ObstacleCollection;
+use crate::world::biomes::winter::regions::snowfield::snow_ground_of;
+use crate::world::biomes::winter::CURRENT_BIOME;
+use crate::world::biomes::{spawn_obstacle, GroundSpawner};
+use crate::world::pool::EntityPool;
+use crate::world::Map;
+
+pub(in super::super) struct SystemIds {
+    pub spawn_ground: SystemId<i32>,
+    pub spawn_obstacles: SystemId<i32>,
+}
+
+impl SystemIds {
+    pub fn register(world: &mut World) -> Self {
+        Self {
+            spawn_ground: world.register_system(Self::spawn_ground),
+            spawn_obstacles: world.register_system(Self::spawn_obstacles),
+        }
+    }
+
+    pub fn unregister(&self, world: &mut World) {
+        world.remove_system(self.spawn_ground).unwrap();
+        world.remove_system(self.spawn_obstacles).unwrap();
+    }
+
+    /// No ice around the spawn point, so the first jumps are always predictable.
+    fn spawn_ground(In(x): In<i32>, mut ground_spawner: GroundSpawner) {
+        ground_spawner.spawn_chunk(CURRENT_BIOME, x, |palette, _| {
+            snow_ground_of(palette, x, false)
+        });
+    }
+
+    fn spawn_obstacles(
+        In(x): In<i32>,
+        mut commands: Commands,
+        mut pool: ResMut<EntityPool>,
+        mut map: ResMut<Map>,
+        obstacles: Res<ObstacleCollection>,
+    ) {
+        // Only the strip behind the spawn point is blocked, the sides are left to the scenery
+        if x > -4 {
+            return;
+        }
+
+        let mut random_generator = map.chunk_random_generator(CURRENT_BIOME, x, "obstacles");
+
+        for z in map.config.gameplay_z_range() {
+            let model = obstacles.trees.get_random(&mut random_generator);
+            let rotation_factor = random_generator.rand_range(1..3) as f32;
+
+            spawn_obstacle(
+                &mut commands,
+                &mut pool,
+                &mut map,
+                ObstacleKind::Tree,
+                model,
+                IVec2::new(x, z),
+                rotation_factor,
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use bevy::app::{App, Plugin, Update};
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::{
+    in_state, Component, Entity, IntoSystemConfigs, Query, Res, ResMut, SystemSet, Transform,
+};
+use bevy::time::Time;
+
+use crate::states::AppState;
+use crate::world::biomes::drift_along_z;
+use crate::world::Map;
+
+pub(super) struct CarrierPlugin;
+
+impl Plugin for CarrierPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (move_carriers, update_platform_cells)
+                .chain()
+                .in_set(CarrierSet)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
+
+/// Moves the carriers and marks the cells they cover.
+/// Anything that reads the platforms of the tile grid must run after it,
+/// otherwise it may see the cells of the previous frame.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub struct CarrierSet;
+
+/// Anything the player can stand on that floats along the z axis, like logs, lily pads or ice floes.
+/// The player who lands on it is carried along until they hop off.
+#[derive(Clone, Copy, Component)]
+pub struct Carrier {
+    /// Signed speed along the z axis in cells per second, zero for carriers that stay in place.
+    pub velocity: f32,
+    /// Size on the ground along the x and the z axis.
+    pub footprint: Vec2,
+}
+
+impl Carrier {
+    /// Spots to stand on are one cell apart and centred on the carrier at `carrier_z`.
+    /// Returns the spot that is `cells` away from the one nearest to `z`.
+    pub fn spot_z(&self, carrier_z: f32, z: f32, cells: f32) -> f32 {
+        let spot_offset = ((self.footprint.y - 1.) / 2.).rem_euclid(1.);
+
+        carrier_z + spot_offset + (z - carrier_z - spot_offset).round() + cells
+    }
+
+    /// Cells covered by the carrier centred at `centre`, given along the x and the z axis.
+    /// A cell is covered if its centre is on the carrier.
+    pub fn covered_cells(&self, centre: Vec2) -> impl Iterator<Item = IVec2> {
+        let min = centre - self.footprint / 2.;
+        let max = centre + self.footprint / 2.;
+
+        (min.x.ceil() as i32..=max.x.floor() as i32)
+            .flat_map(move |x| {
+                (min.y.ceil() as i32..=max.y.floor() as i32).map(move |z| IVec2::new(x, z))
+            })
+            .filter(move |cell| {
+                let cell = cell.as_vec2();
+
+                cell.cmpgt(min).all() && cell.cmplt(max).all()
+            })
+    }
+}
+
+fn move_carriers(time: Res<Time>, map: Res<Map>, mut carriers: Query<(&Carrier, &mut Transform)>) {
+    for (carrier, mut transform) in carriers.iter_mut() {
+        drift_along_z(
+            &map.config,
+            &mut transform.translation,
+            carrier.velocity,
+            carrier.footprint.y,
+            time.delta_seconds(),
+        );
+    }
+}
+
+fn update_platform_cells(mut map: ResMut<Map>, carriers: Query<(Entity, &Carrier, &Transform)>) {
+    map.tiles.clear_platforms();
+
+    for (entity, carrier, transform) in carriers.iter() {
+        let centre = Vec2::new(transform.translation.x, transform.translation.z);
+
+        for cell in carrier.covered_cells(centre) {
+            if let Some(tile) = map.tiles.get_mut(cell.x, cell.y) {
+                tile.platform = Some(entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::{IVec2, Vec2};
+
+    use super::Carrier;
+
+    const LOG: Carrier = Carrier {
+        velocity: 1.,
+        footprint: Vec2::new(0.8, 3.),
+    };
+
+    #[test]
+    fn spots_follow_the_carrier() {
+        let even = Carrier {
+            velocity: 1.,
+            footprint: Vec2::new(0.8, 2.),
+        };
+
+        // Spots of a three cells long carrier are on its centre and a cell to both sides
+        assert_eq!(LOG.spot_z(0.25, 0.5, 0.), 0.25);
+        assert_eq!(LOG.spot_z(0.25, 0.5, 1.), 1.25);
+        assert_eq!(LOG.spot_z(0.25, 1.5, -1.), 0.25);
+        // Spots of a two cells long carrier are half a cell from its centre
+        assert_eq!(even.spot_z(0.25, 0.75, 0.), 0.75);
+        assert_eq!(even.spot_z(0.25, 0.75, -1.), -0.25);
+    }
+
+    #[test]
+    fn footprint_covers_the_cells_under_it() {
+        let cells = LOG.covered_cells(Vec2::new(2., 0.25)).collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            vec![IVec2::new(2, -1), IVec2::new(2, 0), IVec2::new(2, 1)]
+        );
+
+        // Cells whose centre is right on the edge of the carrier are not covered
+        let cells = LOG.covered_cells(Vec2::new(2., 0.5)).collect::<Vec<_>>();
+        assert_eq!(cells, vec![IVec2::new(2, 0), IVec2::new(2, 1)]);
+    }
+}
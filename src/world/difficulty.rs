@@ -0,0 +1,109 @@
+use bevy::prelude::{Res, ResMut, Resource};
+
+use crate::constants::{DIFFICULTY_RAMP_DISTANCE, PLAYER_SPAWN_POINT};
+use crate::resources::biomes::ObstacleKind;
+use crate::world::RequestedRun;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DifficultyMode {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl DifficultyMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "easy" => Some(Self::Easy),
+            "normal" => Some(Self::Normal),
+            "hard" => Some(Self::Hard),
+            _ => None,
+        }
+    }
+
+    /// Scale of the parameters at the spawn point and at the end of the ramp.
+    fn scale_range(self) -> (f32, f32) {
+        match self {
+            Self::Easy => (0.75, 1.),
+            Self::Normal => (1., 1.5),
+            Self::Hard => (1.25, 2.),
+        }
+    }
+}
+
+/// Multipliers of what the biome definitions and constants describe.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DifficultyParams {
+    /// Applies to the weights of everything but empty cells in the obstacle tables.
+    pub obstacle_density: f32,
+    /// Applies to the weights of transitions to hazardous regions, like roads and rivers.
+    pub hazard_chance: f32,
+    pub vehicle_speed: f32,
+    pub train_speed: f32,
+}
+
+impl DifficultyParams {
+    /// The obstacle weight table with the density applied.
+    pub fn obstacle_weights(&self, weights: &[(ObstacleKind, u32)]) -> Vec<(ObstacleKind, u32)> {
+        weights
+            .iter()
+            .map(|(kind, weight)| match kind {
+                ObstacleKind::Empty => (*kind, *weight),
+                _ => (*kind, scale_weight(*weight, self.obstacle_density)),
+            })
+            .collect()
+    }
+
+    /// The weight of a region transition with the hazard chance applied.
+    pub fn region_weight(&self, weight: u32, is_hazard: bool) -> u32 {
+        match is_hazard {
+            true => scale_weight(weight, self.hazard_chance),
+            false => weight,
+        }
+    }
+}
+
+/// How hard the current run is, it grows with the distance from the spawn point.
+#[derive(Clone, Copy, Debug, Default, Resource)]
+pub struct Difficulty {
+    pub mode: DifficultyMode,
+}
+
+impl Difficulty {
+    /// Parameters once the player has reached `x`.
+    ///
+    /// Chunks are generated ahead of the player with the parameters at their own x,
+    /// rather than at the furthest x the player has reached so far,
+    /// so the same seed and mode always generate the same world.
+    pub fn at(&self, x: i32) -> DifficultyParams {
+        let (start, end) = self.mode.scale_range();
+        let progress = ((x - PLAYER_SPAWN_POINT.x as i32) as f32 / DIFFICULTY_RAMP_DISTANCE as f32)
+            .clamp(0., 1.);
+        let scale = start + (end - start) * progress;
+
+        DifficultyParams {
+            obstacle_density: scale,
+            hazard_chance: scale,
+            // Speeds grow slower, since they also shorten the time to react
+            vehicle_speed: 1. + (scale - 1.) / 2.,
+            train_speed: 1. + (scale - 1.) / 2.,
+        }
+    }
+
+    pub(super) fn reset(mut difficulty: ResMut<Self>, requested_run: Res<RequestedRun>) {
+        *difficulty = Self {
+            mode: requested_run.difficulty.unwrap_or_default(),
+        };
+
+        info!("Difficulty: {:?}", difficulty.mode);
+    }
+}
+
+/// Weights never drop to zero, so nothing disappears from the tables completely.
+fn scale_weight(weight: u32, scale: f32) -> u32 {
+    match weight {
+        0 => 0,
+        _ => ((weight as f32 * scale).round() as u32).max(1),
+    }
+}
@@ -1,26 +1,46 @@
 use std::collections::VecDeque;
+use std::ops::{Range, RangeInclusive};
 
-use bevy::app::{App, Plugin, Update};
-use bevy::math::IVec2;
+use bevy::app::{App, Plugin, PreUpdate, Update};
+use bevy::asset::Assets;
 #[cfg(feature = "debug")]
 use bevy::prelude::EventReader;
 use bevy::prelude::{
-    in_state, Entity, EventWriter, IntoSystemConfigs, NextState, OnEnter, Query, Res, ResMut,
-    Resource, State, ViewVisibility,
+    in_state, Commands, Entity, EventWriter, IntoSystemConfigs, NextState, OnEnter, Query, Res,
+    ResMut, Resource, State, Transform, With,
 };
-use bevy::utils::HashMap;
+use bevy::utils::HashSet;
 use oorandom::Rand32;
 
+use crate::camera::Camera;
+use crate::constants::{
+    BIOME_TRANSITION_DISTANCE, MAP_GAMEPLAY_MAX_Z, MAP_GAMEPLAY_MIN_Z, MAP_LOOK_AHEAD_DISTANCE,
+    MAP_LOOK_BEHIND_DISTANCE, MAP_MAX_Z, MAP_MIN_X, MAP_MIN_Z, PLAYER_SPAWN_POINT,
+};
 #[cfg(feature = "debug")]
 use crate::events::DevRequestBiome;
 use crate::events::{RequestNewChunkSpawning, RequestOldChunkDespawning};
-use crate::player::CurrentCharacter;
+use crate::player::{CurrentCharacter, Player, RunProgress};
+use crate::resources::biomes::{BiomeCollection, BiomeDefinition, PhysicsProfile};
 use crate::states::{AppState, CurrentBiome};
+use crate::utils;
 use crate::world::biomes::crossy_valley::CrossyValleyBiome;
 #[cfg(feature = "debug")]
 use crate::world::biomes::default::DefaultBiome;
+use crate::world::biomes::moon::MoonBiome;
+use crate::world::biomes::winter::WinterBiome;
+use crate::world::carriers::CarrierPlugin;
+use crate::world::difficulty::{Difficulty, DifficultyMode};
+use crate::world::pool::{EntityPool, Pooled};
+use crate::world::tiles::TileGrid;
 
 mod biomes;
+pub mod carriers;
+pub mod difficulty;
+pub mod pool;
+#[cfg(test)]
+mod tests;
+pub mod tiles;
 
 pub struct WorldPlugin;
 
@@ -30,11 +50,27 @@ impl Plugin for WorldPlugin {
         app.add_plugins(DefaultBiome);
 
         app.init_resource::<Map>()
-            .add_plugins(CrossyValleyBiome)
-            .add_systems(OnEnter(AppState::InitialisingWorld), init_world)
+            .init_resource::<PhysicsProfile>()
+            .init_resource::<EntityPool>()
+            .init_resource::<ChunkStreaming>()
+            .init_resource::<MapConfig>()
+            .init_resource::<Difficulty>()
+            .insert_resource(RequestedRun::from_settings())
+            .add_plugins((CarrierPlugin, CrossyValleyBiome, WinterBiome, MoonBiome))
+            .add_systems(
+                OnEnter(AppState::InitialisingWorld),
+                (
+                    (init_world, init_map, request_initial_chunks).chain(),
+                    Difficulty::reset,
+                ),
+            )
+            .add_systems(OnEnter(AppState::Clearing), clear_world)
+            // Requests are handled by the biomes during the same frame,
+            // so the next check already sees the spawned and despawned chunks
+            .add_systems(PreUpdate, stream_chunks.run_if(in_state(AppState::Playing)))
             .add_systems(
                 Update,
-                check_chunk_visibilities.run_if(in_state(AppState::Playing)),
+                update_physics_profile.run_if(in_state(AppState::Playing)),
             );
     }
 }
@@ -42,90 +78,341 @@ impl Plugin for WorldPlugin {
 #[derive(Clone)]
 pub struct Chunk {
     pub position_x: i32,
+    /// Biome that spawned the chunk. Chunks of the previous biome
+    /// stay on the map after a transition until they are despawned.
+    pub biome: CurrentBiome,
     pub entities: Vec<Entity>,
 }
 
-#[derive(Clone, Resource)]
-pub struct Map {
-    pub random_generator: Rand32,
-    pub chunks: VecDeque<Chunk>,
-    pub obstacles_xz: HashMap<IVec2, Entity>,
+/// How far from the player chunks are kept spawned, in chunks.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct ChunkStreaming {
+    pub look_ahead: i32,
+    pub look_behind: i32,
+}
+
+impl Default for ChunkStreaming {
+    fn default() -> Self {
+        Self {
+            look_ahead: MAP_LOOK_AHEAD_DISTANCE,
+            look_behind: MAP_LOOK_BEHIND_DISTANCE,
+        }
+    }
+}
+
+/// Dimensions of the playfield that the next run is generated with,
+/// so game modes can narrow or widen it. A run keeps the dimensions it started with, see [Map::config].
+#[derive(Clone, Copy, Debug, PartialEq, Resource)]
+pub struct MapConfig {
+    /// Position of the first chunk of a run.
+    pub min_x: i32,
+    /// Chunks span the cells from `min_z` up to, but not including, `max_z`.
+    pub min_z: i32,
+    pub max_z: i32,
+    /// The player can only move from `gameplay_min_z` to `gameplay_max_z`, inclusive.
+    /// The cells around them are dimmed and only decorated.
+    pub gameplay_min_z: i32,
+    pub gameplay_max_z: i32,
 }
 
-impl Default for Map {
+impl Default for MapConfig {
     fn default() -> Self {
         Self {
-            random_generator: Rand32::new(0),
-            chunks: Default::default(),
-            obstacles_xz: Default::default(),
+            min_x: MAP_MIN_X,
+            min_z: MAP_MIN_Z,
+            max_z: MAP_MAX_Z,
+            gameplay_min_z: MAP_GAMEPLAY_MIN_Z,
+            gameplay_max_z: MAP_GAMEPLAY_MAX_Z,
+        }
+    }
+}
+
+impl MapConfig {
+    pub fn z_range(&self) -> Range<i32> {
+        self.min_z..self.max_z
+    }
+
+    pub fn gameplay_z_range(&self) -> RangeInclusive<i32> {
+        self.gameplay_min_z..=self.gameplay_max_z
+    }
+
+    pub fn is_gameplay_z(&self, z: i32) -> bool {
+        self.gameplay_z_range().contains(&z)
+    }
+
+    /// The gameplay strip must lie within the chunks and contain the spawn point,
+    /// and the first chunk must be behind the spawn point.
+    fn is_valid(&self) -> bool {
+        let spawn_z = PLAYER_SPAWN_POINT.z as i32;
+
+        self.min_z <= self.gameplay_min_z
+            && self.gameplay_max_z < self.max_z
+            && self.is_gameplay_z(spawn_z)
+            && self.min_x < PLAYER_SPAWN_POINT.x as i32
+    }
+}
+
+/// Settings that every run uses instead of the random or character defaults.
+#[derive(Clone, Copy, Debug, Default, Resource)]
+pub struct RequestedRun {
+    pub seed: Option<u64>,
+    /// Starting biome instead of the one of the current character.
+    pub biome: Option<CurrentBiome>,
+    /// [DifficultyMode::Normal] by default.
+    pub difficulty: Option<DifficultyMode>,
+}
+
+impl RequestedRun {
+    /// Reads the command line arguments and environment variables.
+    pub fn from_settings() -> Self {
+        let biome = utils::get_requested_biome().and_then(|name| {
+            let biome = CurrentBiome::from_name(&name);
+
+            if biome.is_none() {
+                warn!("Ignoring unknown biome: {name}");
+            }
+
+            biome
+        });
+
+        let difficulty = utils::get_requested_difficulty().and_then(|name| {
+            let difficulty = DifficultyMode::from_name(&name);
+
+            if difficulty.is_none() {
+                warn!("Ignoring unknown difficulty: {name}");
+            }
+
+            difficulty
+        });
+
+        Self {
+            seed: utils::get_requested_seed(),
+            biome,
+            difficulty,
+        }
+    }
+}
+
+#[derive(Clone, Default, Resource)]
+pub struct Map {
+    /// Seed of the current run. The same seed always produces the same world.
+    pub seed: u64,
+    /// Dimensions of the current run, taken from [MapConfig] when the world is initialised.
+    pub config: MapConfig,
+    pub chunks: VecDeque<Chunk>,
+    /// What is on every cell of the spawned chunks.
+    pub tiles: TileGrid,
+    /// The last validated row and its gameplay cells that the player can reach.
+    pub reachable_row: Option<(i32, HashSet<i32>)>,
+    /// The biome that takes over spawning from the chunk at this x.
+    pub next_biome: Option<(i32, CurrentBiome)>,
+}
+
+impl Map {
+    /// Random generator that only depends on the seed, the biome, the chunk position
+    /// and the purpose, so any chunk can be generated alone and in any order.
+    ///
+    /// Different purposes of the same chunk give unrelated generators,
+    /// so adding random calls to one spawning system doesn't change the others.
+    pub fn chunk_random_generator(&self, biome: CurrentBiome, x: i32, purpose: &str) -> Rand32 {
+        Rand32::new(utils::mix_seed(&[
+            self.seed,
+            biome.seed_key(),
+            x as u64,
+            utils::hash_str(purpose),
+        ]))
+    }
+
+    pub fn next_chunk_x(&self) -> i32 {
+        match self.chunks.back() {
+            Some(chunk) => chunk.position_x + 1,
+            None => self.config.min_x,
         }
     }
+
+    /// Chunks that the current biome should spawn for `count` requests.
+    /// Chunks after the biome transition are left to the next biome.
+    pub fn chunks_to_spawn(&self, count: usize) -> Range<i32> {
+        let from_x = self.next_chunk_x();
+        let to_x = match self.next_biome {
+            Some((transition_x, _)) => (from_x + count as i32).min(transition_x),
+            None => from_x + count as i32,
+        };
+
+        from_x..to_x.max(from_x)
+    }
+
+    /// Plans the transition from `biome` entered at `x` to another playable biome.
+    /// There is no transition if the run can't cross into any other biome.
+    pub fn plan_next_biome(&mut self, biome: CurrentBiome, x: i32) {
+        let mut candidates = CurrentBiome::all_playable();
+
+        self.next_biome = None;
+
+        if !candidates.contains(&biome) {
+            return;
+        }
+
+        candidates.retain(|candidate| *candidate != biome);
+
+        if candidates.is_empty() {
+            return;
+        }
+
+        let transition_x = x + BIOME_TRANSITION_DISTANCE;
+        let mut random_generator =
+            self.chunk_random_generator(biome, transition_x, "biome transition");
+        let index = random_generator.rand_range(0..candidates.len() as u32) as usize;
+
+        self.next_biome = Some((transition_x, candidates[index]));
+    }
 }
 
 fn init_world(
     #[cfg(feature = "debug")] mut biome_dev_requests: EventReader<DevRequestBiome>,
-    mut chunk_generation_requester: EventWriter<RequestNewChunkSpawning>,
     mut app_state: ResMut<NextState<AppState>>,
     mut current_biome_setter: ResMut<NextState<CurrentBiome>>,
     mut map: ResMut<Map>,
     current_biome: Res<State<CurrentBiome>>,
     current_character: Res<CurrentCharacter>,
+    requested_run: Res<RequestedRun>,
 ) {
     if current_biome.get().eq(&CurrentBiome::None) {
+        let biome = requested_run
+            .biome
+            .unwrap_or_else(|| current_character.get().biome);
+
         #[cfg(feature = "debug")]
         match biome_dev_requests.read().next() {
             Some(biome) if biome.get().ne(&CurrentBiome::None) => {
                 current_biome_setter.set(biome.get())
             }
-            _ => current_biome_setter.set(current_character.get().biome),
+            _ => current_biome_setter.set(biome),
         };
 
         #[cfg(not(feature = "debug"))]
-        current_biome_setter.set(current_character.get().biome);
+        current_biome_setter.set(biome);
     }
 
-    map.random_generator = Rand32::new(0);
+    map.seed = requested_run.seed.unwrap_or_else(utils::generate_seed);
 
-    for _ in 0..16 {
+    info!("World seed: {}", map.seed);
+
+    app_state.set(AppState::Playing);
+}
+
+/// Copies the config into the map, an invalid one falls back to the default dimensions.
+fn init_map(mut map: ResMut<Map>, map_config: Res<MapConfig>) {
+    map.config = match map_config.is_valid() {
+        true => *map_config,
+        false => {
+            warn!("Ignoring invalid map config: {:?}", *map_config);
+            MapConfig::default()
+        }
+    };
+    map.tiles = TileGrid::new(map.config.z_range());
+}
+
+/// The player must land on the ground, so the first chunks can't wait for streaming.
+fn request_initial_chunks(
+    mut chunk_generation_requester: EventWriter<RequestNewChunkSpawning>,
+    map: Res<Map>,
+    streaming: Res<ChunkStreaming>,
+) {
+    let initial_chunks = PLAYER_SPAWN_POINT.x as i32 + streaming.look_ahead - map.config.min_x + 1;
+
+    for _ in 0..initial_chunks {
         chunk_generation_requester.send(RequestNewChunkSpawning);
     }
+}
 
-    app_state.set(AppState::Playing);
+/// Despawns everything that chunks of any biome have left on the map.
+/// Grounds and obstacles go back to the pool, so the next run reuses them.
+fn clear_world(
+    mut commands: Commands,
+    mut map: ResMut<Map>,
+    pool: Res<EntityPool>,
+    pooled: Query<&Pooled>,
+) {
+    // Grounds, vehicles, etc. are always stored in chunks
+    let chunk_entities = map.chunks.iter().flat_map(|chunk| chunk.entities.iter());
+
+    let obstacles = map
+        .tiles
+        .tiles()
+        .filter_map(|tile| tile.obstacle.as_ref().map(|obstacle| &obstacle.entity));
+
+    for entity in obstacles.chain(chunk_entities) {
+        EntityPool::release(&mut commands, *entity, &pooled);
+    }
+
+    info!(
+        "Entity pool: {} hits, {} misses, {} idle",
+        pool.hits,
+        pool.misses,
+        pool.idle_count()
+    );
+
+    map.chunks.clear();
+    map.tiles.clear();
+    map.reachable_row = None;
+    map.next_biome = None;
+}
+
+/// The player moves by the rules of the biome that spawned the chunk they are on,
+/// even if the next biome has already started spawning ahead.
+fn update_physics_profile(
+    mut physics: ResMut<PhysicsProfile>,
+    map: Res<Map>,
+    biomes: Res<BiomeCollection>,
+    definitions: Res<Assets<BiomeDefinition>>,
+    players: Query<&Transform, With<Player>>,
+) {
+    let Some(player_transform) = players.iter().next() else {
+        return;
+    };
+    let player_x = player_transform.translation.x.round() as i32;
+    let Some(chunk) = map.chunks.iter().find(|chunk| chunk.position_x == player_x) else {
+        return;
+    };
+    let Some(definition) = biomes.get(chunk.biome, &definitions) else {
+        return;
+    };
+
+    if *physics != definition.physics {
+        *physics = definition.physics.clone();
+    }
 }
 
-fn check_chunk_visibilities(
+/// Keeps chunks spawned from the look-behind distance to the look-ahead distance
+/// around the player, or around the camera if there is no player.
+/// Chunks that the player can still go back to are never despawned.
+fn stream_chunks(
     mut new_chunk_spawning_requester: EventWriter<RequestNewChunkSpawning>,
     mut old_chunk_despawning_requester: EventWriter<RequestOldChunkDespawning>,
-    map: ResMut<Map>,
-    entities: Query<&ViewVisibility>,
+    map: Res<Map>,
+    streaming: Res<ChunkStreaming>,
+    progress: Res<RunProgress>,
+    players: Query<&Transform, With<Player>>,
+    cameras: Query<&Transform, With<Camera>>,
 ) {
-    // Checks to create new chunks
-    if let Some(chunk) = map.chunks.back() {
-        if chunk
-            .entities
-            .iter()
-            .any(|entity| match entities.get(*entity) {
-                Ok(visibility) => visibility.get(),
-                Err(_) => false,
-            })
-        {
-            new_chunk_spawning_requester.send(RequestNewChunkSpawning);
-        }
-    }
+    let Some(transform) = players.iter().chain(cameras.iter()).next() else {
+        return;
+    };
+    let focus_x = transform.translation.x.round() as i32;
 
-    // Checks to delete old chunks
-    if let Some(chunk) = map.chunks.front() {
-        if chunk
-            .entities
-            .iter()
-            .any(|entity| match entities.get(*entity) {
-                Ok(visibility) => visibility.get(),
-                Err(_) => true,
-            })
-        {
-            return;
-        }
+    let missing_chunks = focus_x + streaming.look_ahead - map.next_chunk_x() + 1;
+    for _ in 0..missing_chunks.max(0) {
+        new_chunk_spawning_requester.send(RequestNewChunkSpawning);
+    }
 
+    let first_kept_x = (focus_x - streaming.look_behind).min(progress.min_reachable_x());
+    let old_chunks = map
+        .chunks
+        .iter()
+        .take_while(|chunk| chunk.position_x < first_kept_x)
+        .count();
+    for _ in 0..old_chunks {
         old_chunk_despawning_requester.send(RequestOldChunkDespawning);
     }
 }
@@ -0,0 +1,83 @@
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Component, Entity, Query, Resource, Visibility, World};
+use bevy_rapier3d::geometry::ColliderDisabled;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolKind {
+    Ground,
+    Obstacle,
+    Scenery,
+    Decoration,
+}
+
+/// Marks entities that are returned to the [EntityPool] instead of being despawned.
+#[derive(Clone, Copy, Component)]
+pub struct Pooled(pub PoolKind);
+
+/// Hidden entities that are waiting to be moved to a new chunk.
+/// They keep all their components, so only the mesh, material and transform are swapped.
+#[derive(Default, Resource)]
+pub struct EntityPool {
+    grounds: Vec<Entity>,
+    obstacles: Vec<Entity>,
+    scenery: Vec<Entity>,
+    decorations: Vec<Entity>,
+    /// Entities taken from the pool
+    pub hits: u64,
+    /// Entities that had to be spawned, because the pool was empty
+    pub misses: u64,
+}
+
+impl EntityPool {
+    /// The caller must show the entity again and enable its collider.
+    pub fn acquire(&mut self, kind: PoolKind) -> Option<Entity> {
+        let entity = match kind {
+            PoolKind::Ground => self.grounds.pop(),
+            PoolKind::Obstacle => self.obstacles.pop(),
+            PoolKind::Scenery => self.scenery.pop(),
+            PoolKind::Decoration => self.decorations.pop(),
+        };
+
+        match entity {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+
+        entity
+    }
+
+    /// Entities that are hidden and waiting to be reused.
+    pub fn idle_count(&self) -> usize {
+        self.grounds.len() + self.obstacles.len() + self.scenery.len() + self.decorations.len()
+    }
+
+    /// Pooled entities are hidden and returned to the pool, anything else is despawned.
+    ///
+    /// The entity only gets into the pool when the commands are applied,
+    /// so it can't be acquired while it's still visible.
+    pub fn release(commands: &mut Commands, entity: Entity, pooled: &Query<&Pooled>) {
+        let Ok(Pooled(kind)) = pooled.get(entity).copied() else {
+            if let Some(entity) = commands.get_entity(entity) {
+                entity.despawn_recursive();
+            }
+
+            return;
+        };
+
+        commands.add(move |world: &mut World| {
+            let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                return;
+            };
+
+            entity_mut.insert((Visibility::Hidden, ColliderDisabled));
+
+            let mut pool = world.resource_mut::<EntityPool>();
+            match kind {
+                PoolKind::Ground => pool.grounds.push(entity),
+                PoolKind::Obstacle => pool.obstacles.push(entity),
+                PoolKind::Scenery => pool.scenery.push(entity),
+                PoolKind::Decoration => pool.decorations.push(entity),
+            }
+        });
+    }
+}
@@ -0,0 +1,106 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::mapgen;
+use crate::states::CurrentBiome;
+use crate::world::difficulty::DifficultyMode;
+use crate::world::{MapConfig, RequestedRun};
+
+const SNAPSHOT_CHUNK_COUNT: usize = 40;
+
+/// Compares the generated map with `src/world/snapshots/{name}.txt`.
+/// Run the tests with `UPDATE_SNAPSHOTS=1` to accept intentional generation changes.
+fn assert_snapshot(name: &str, biome: CurrentBiome, seed: u64, map_config: MapConfig) {
+    let requested_run = RequestedRun {
+        seed: Some(seed),
+        biome: Some(biome),
+        difficulty: Some(DifficultyMode::Normal),
+    };
+    let map = mapgen::generate(requested_run, map_config, SNAPSHOT_CHUNK_COUNT)
+        .expect("Failed to generate the map in time");
+    let ascii = mapgen::to_ascii(&map, SNAPSHOT_CHUNK_COUNT);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/world/snapshots")
+        .join(format!("{name}.txt"));
+
+    if env::var("UPDATE_SNAPSHOTS").is_ok_and(|value| value == "1") {
+        fs::write(&path, &ascii).expect("Failed to write the snapshot");
+        return;
+    }
+
+    let snapshot = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Missing snapshot {}", path.display()))
+        .replace("\r\n", "\n");
+
+    assert!(
+        snapshot == ascii,
+        "Generated map differs from {}\n\nExpected:\n{snapshot}\nGenerated:\n{ascii}",
+        path.display(),
+    );
+}
+
+#[test]
+fn crossy_valley_seed_1() {
+    assert_snapshot(
+        "crossy_valley_seed_1",
+        CurrentBiome::CrossyValley,
+        1,
+        MapConfig::default(),
+    );
+}
+
+#[test]
+fn crossy_valley_seed_42() {
+    assert_snapshot(
+        "crossy_valley_seed_42",
+        CurrentBiome::CrossyValley,
+        42,
+        MapConfig::default(),
+    );
+}
+
+#[test]
+fn crossy_valley_seed_31337() {
+    assert_snapshot(
+        "crossy_valley_seed_31337",
+        CurrentBiome::CrossyValley,
+        31337,
+        MapConfig::default(),
+    );
+}
+
+#[test]
+fn crossy_valley_narrow_seed_1() {
+    let map_config = MapConfig {
+        min_z: -4,
+        max_z: 4,
+        gameplay_min_z: -2,
+        gameplay_max_z: 2,
+        ..Default::default()
+    };
+
+    assert_snapshot(
+        "crossy_valley_narrow_seed_1",
+        CurrentBiome::CrossyValley,
+        1,
+        map_config,
+    );
+}
+
+#[test]
+fn winter_single_lane_seed_1() {
+    let map_config = MapConfig {
+        gameplay_min_z: 0,
+        gameplay_max_z: 0,
+        ..Default::default()
+    };
+
+    assert_snapshot(
+        "winter_single_lane_seed_1",
+        CurrentBiome::Winter,
+        1,
+        map_config,
+    );
+}
@@ -0,0 +1,256 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use bevy::prelude::Entity;
+use serde::Serialize;
+
+use crate::resources::biomes::ObstacleKind;
+use crate::world::MapConfig;
+
+/// What the ground of a cell is made of.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize)]
+pub enum TileKind {
+    /// Grass, snow, lunar dust and anything else the player simply stands on.
+    #[default]
+    Ground,
+    /// Makes the player slide one more cell after landing.
+    Ice,
+    Road,
+    Rail,
+    /// Kills the player on landing, unless there is a platform on it.
+    Water,
+}
+
+impl TileKind {
+    pub fn is_lethal(self) -> bool {
+        self == Self::Water
+    }
+
+    pub fn is_slippery(self) -> bool {
+        self == Self::Ice
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TileObstacle {
+    pub kind: ObstacleKind,
+    pub entity: Entity,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Tile {
+    pub kind: TileKind,
+    /// Blocks the cell, the player can't move onto it.
+    pub obstacle: Option<TileObstacle>,
+    /// Moving platform that currently covers the cell.
+    /// Must be updated every frame by the regions that spawn these platforms.
+    pub platform: Option<Entity>,
+    /// Collected when the player lands on the cell, the entity itself belongs to the chunk.
+    pub coin: Option<Entity>,
+}
+
+impl Tile {
+    pub fn is_lethal(&self) -> bool {
+        self.kind.is_lethal() && self.platform.is_none()
+    }
+}
+
+/// Tiles of the spawned chunks, one row of cells along the z axis per chunk.
+/// Rows are added at the back as chunks are spawned and removed from the front.
+#[derive(Clone, Debug)]
+pub struct TileGrid {
+    first_x: i32,
+    /// Cells of every row.
+    z_range: Range<i32>,
+    rows: VecDeque<Vec<Tile>>,
+}
+
+impl Default for TileGrid {
+    fn default() -> Self {
+        Self::new(MapConfig::default().z_range())
+    }
+}
+
+impl TileGrid {
+    pub fn new(z_range: Range<i32>) -> Self {
+        Self {
+            first_x: 0,
+            z_range,
+            rows: VecDeque::new(),
+        }
+    }
+
+    fn row_length(&self) -> usize {
+        self.z_range.len()
+    }
+
+    fn row_index(&self, x: i32) -> Option<usize> {
+        let index = usize::try_from(x - self.first_x).ok()?;
+
+        (index < self.rows.len()).then_some(index)
+    }
+
+    fn cell_index(&self, z: i32) -> Option<usize> {
+        let index = usize::try_from(z - self.z_range.start).ok()?;
+
+        (index < self.row_length()).then_some(index)
+    }
+
+    /// Replaces the row with tiles of the given kinds, `kind_of` is called for every z.
+    /// Rows are expected in order, any skipped rows are filled with plain ground.
+    pub fn insert_row(&mut self, x: i32, kind_of: impl Fn(i32) -> TileKind) {
+        if self.rows.is_empty() {
+            self.first_x = x;
+        }
+
+        if x < self.first_x {
+            warn!("Row {} was already removed from the tile grid", x);
+            return;
+        }
+
+        while self.row_index(x).is_none() {
+            self.rows
+                .push_back(vec![Tile::default(); self.row_length()]);
+        }
+
+        let index = x - self.first_x;
+        self.rows[index as usize] = self
+            .z_range
+            .clone()
+            .map(|z| Tile {
+                kind: kind_of(z),
+                ..Default::default()
+            })
+            .collect();
+    }
+
+    /// Removes the row and all rows before it, returning their tiles.
+    pub fn remove_rows_through(&mut self, x: i32) -> Vec<Tile> {
+        let mut tiles = Vec::new();
+
+        while self.first_x <= x {
+            let Some(row) = self.rows.pop_front() else {
+                break;
+            };
+
+            tiles.extend(row);
+            self.first_x += 1;
+        }
+
+        tiles
+    }
+
+    pub fn clear(&mut self) {
+        self.rows.clear();
+    }
+
+    /// `None` for cells outside the spawned chunks.
+    pub fn get(&self, x: i32, z: i32) -> Option<&Tile> {
+        self.rows.get(self.row_index(x)?)?.get(self.cell_index(z)?)
+    }
+
+    pub fn get_mut(&mut self, x: i32, z: i32) -> Option<&mut Tile> {
+        let row_index = self.row_index(x)?;
+        let cell_index = self.cell_index(z)?;
+
+        self.rows.get_mut(row_index)?.get_mut(cell_index)
+    }
+
+    /// The player can stand on the cell, though it may still be lethal.
+    pub fn is_walkable(&self, x: i32, z: i32) -> bool {
+        self.get(x, z).is_some_and(|tile| tile.obstacle.is_none())
+    }
+
+    /// Cells of the row with their z, empty if the row isn't spawned.
+    pub fn tiles_in_row(&self, x: i32) -> impl Iterator<Item = (i32, &Tile)> {
+        let min_z = self.z_range.start;
+
+        self.row_index(x)
+            .and_then(|index| self.rows.get(index))
+            .into_iter()
+            .flat_map(move |row| (min_z..).zip(row.iter()))
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &Tile> {
+        self.rows.iter().flatten()
+    }
+
+    pub fn clear_platforms(&mut self) {
+        for tile in self.rows.iter_mut().flatten() {
+            tile.platform = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TileGrid, TileKind};
+
+    fn kind_at(tiles: &TileGrid, x: i32, z: i32) -> Option<TileKind> {
+        tiles.get(x, z).map(|tile| tile.kind)
+    }
+
+    #[test]
+    fn skipped_rows_are_filled_with_ground() {
+        let mut tiles = TileGrid::new(-1..2);
+        tiles.insert_row(3, |_| TileKind::Water);
+        tiles.insert_row(5, |_| TileKind::Road);
+
+        assert_eq!(kind_at(&tiles, 3, -1), Some(TileKind::Water));
+        assert_eq!(kind_at(&tiles, 4, 0), Some(TileKind::Ground));
+        assert_eq!(kind_at(&tiles, 5, 1), Some(TileKind::Road));
+        assert_eq!(tiles.tiles().count(), 9);
+    }
+
+    #[test]
+    fn removed_rows_are_never_inserted_again() {
+        let mut tiles = TileGrid::new(-1..2);
+
+        for x in 0..3 {
+            tiles.insert_row(x, |_| TileKind::Rail);
+        }
+
+        assert_eq!(tiles.remove_rows_through(1).len(), 6);
+        assert_eq!(kind_at(&tiles, 1, 0), None);
+        assert_eq!(kind_at(&tiles, 2, 0), Some(TileKind::Rail));
+
+        // Already removed, so it's ignored with a warning
+        tiles.insert_row(1, |_| TileKind::Water);
+        assert_eq!(kind_at(&tiles, 1, 0), None);
+        assert_eq!(tiles.tiles().count(), 3);
+
+        // The next row still follows the remaining ones
+        tiles.insert_row(3, |_| TileKind::Ice);
+        assert_eq!(kind_at(&tiles, 2, 0), Some(TileKind::Rail));
+        assert_eq!(kind_at(&tiles, 3, 0), Some(TileKind::Ice));
+
+        // Removing past the last row only removes what is there
+        assert_eq!(tiles.remove_rows_through(10).len(), 6);
+        assert_eq!(tiles.tiles().count(), 0);
+    }
+
+    #[test]
+    fn cells_outside_the_grid_are_missing() {
+        let mut tiles = TileGrid::new(-1..2);
+        tiles.insert_row(4, |_| TileKind::Ice);
+        tiles.insert_row(5, |_| TileKind::Ice);
+
+        for (x, z) in [(3, 0), (6, 0), (4, -2), (4, 2)] {
+            assert!(
+                tiles.get(x, z).is_none(),
+                "Cell {x}, {z} is outside the grid"
+            );
+            assert!(
+                tiles.get_mut(x, z).is_none(),
+                "Cell {x}, {z} is outside the grid"
+            );
+        }
+
+        assert_eq!(tiles.tiles_in_row(3).count(), 0);
+        assert_eq!(tiles.tiles_in_row(6).count(), 0);
+        assert_eq!(
+            tiles.tiles_in_row(5).map(|(z, _)| z).collect::<Vec<_>>(),
+            vec![-1, 0, 1]
+        );
+    }
+}